@@ -1,6 +1,7 @@
 use std::{
     cmp::Ordering,
     fmt::Display,
+    io::Write,
 };
 
 use log::info;
@@ -9,6 +10,7 @@ use rand::{
     prelude::Distribution,
     Rng,
 };
+use serde::Serialize;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Action {
@@ -27,14 +29,93 @@ impl Display for Action {
     }
 }
 
+/// How a battlefield win is scored.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PayoffMode {
+    /// Collapse the per-battlefield result to the sign of the weighted claim
+    /// difference: +1 / 0 / -1. This is the classic Colonel Blotto objective.
+    Majority,
+    /// Return the signed sum of claimed battlefield weights, so winning more
+    /// valuable fields by a larger margin pays more. Models lottery-Blotto.
+    Continuous,
+}
+
+/// How an overall tie (equal weighted claims) is resolved under
+/// [`PayoffMode::Majority`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TieRule {
+    /// The game is a draw, worth 0 to both players.
+    Draw,
+    /// The player whose payoff is computed (`a` in [`PayoffConfig::payoff`])
+    /// takes ties, worth +1. [`Trainer`] scores each side from its own seat, so
+    /// under this rule both sides count a tie as +1.
+    FavorAttacker,
+}
+
+/// Configures the Blotto objective: a per-battlefield weight vector, the scoring
+/// mode, and the overall-tie rule. `weights.len()` is the battlefield count.
+#[derive(Clone, Debug)]
+pub struct PayoffConfig {
+    pub weights: Vec<i64>,
+    pub mode: PayoffMode,
+    pub tie_rule: TieRule,
+}
+
+impl PayoffConfig {
+    /// The plain symmetric game: every battlefield worth 1, majority rule, ties
+    /// drawn.
+    pub fn symmetric(battlefields_count: u32) -> Self {
+        Self {
+            weights: vec![1; battlefields_count as usize],
+            mode: PayoffMode::Majority,
+            tie_rule: TieRule::Draw,
+        }
+    }
+
+    fn battlefields_count(&self) -> usize {
+        self.weights.len()
+    }
+
+    /// The payoff to the player playing `a` against an opponent playing `b`.
+    pub fn payoff(&self, a: &Action, b: &Action) -> i64 {
+        debug_assert_eq!(a.assignments.len(), b.assignments.len());
+        debug_assert_eq!(a.assignments.len(), self.weights.len());
+
+        // Signed sum of the weights of the battlefields each player claims;
+        // tied battlefields are claimed by neither.
+        let mut claimed: i64 = 0;
+        for i in 0..a.assignments.len() {
+            claimed += match a.assignments[i].cmp(&b.assignments[i]) {
+                Ordering::Greater => self.weights[i],
+                Ordering::Less => -self.weights[i],
+                Ordering::Equal => 0,
+            };
+        }
+
+        match self.mode {
+            PayoffMode::Continuous => claimed,
+            PayoffMode::Majority => match claimed.cmp(&0) {
+                Ordering::Greater => 1,
+                Ordering::Less => -1,
+                Ordering::Equal => match self.tie_rule {
+                    TieRule::Draw => 0,
+                    TieRule::FavorAttacker => 1,
+                },
+            },
+        }
+    }
+}
+
 pub struct Trainer {
     valid_actions: Vec<Action>,
     player_regret: Regret,
     opponent_regret: Regret,
+    payoff: PayoffConfig,
 }
 
 impl Trainer {
-    pub fn new(soldiers_count: u32, battlefields_count: u32) -> Self {
+    pub fn new(soldiers_count: u32, payoff: PayoffConfig) -> Self {
+        let battlefields_count = payoff.battlefields_count() as u32;
         let valid_actions = Self::list_valid_actions(soldiers_count, battlefields_count);
         let player_regret = Regret::new(valid_actions.len());
         let opponent_regret = Regret::new(valid_actions.len());
@@ -42,6 +123,7 @@ impl Trainer {
             valid_actions,
             player_regret,
             opponent_regret,
+            payoff,
         }
     }
 
@@ -62,12 +144,17 @@ impl Trainer {
                 self.opponent_regret
                     .get_action(&mut rng, &opponent_strategy, &self.valid_actions);
 
-            self.player_regret
-                .update_regret(&player_action, &opponent_action, &self.valid_actions);
+            self.player_regret.update_regret(
+                &player_action,
+                &opponent_action,
+                &self.valid_actions,
+                &self.payoff,
+            );
             self.opponent_regret.update_regret(
                 &opponent_action,
                 &player_action,
                 &self.valid_actions,
+                &self.payoff,
             );
 
             if i % 1000 == 0 {
@@ -77,6 +164,22 @@ impl Trainer {
         self.print_avg_strategy();
     }
 
+    /// A stable, serializable view of the solved player-0 strategy: each action
+    /// is its per-battlefield troop assignment, alongside the converged average
+    /// strategy probability and cumulative regret.
+    pub fn to_record(&self) -> StrategyRecord {
+        StrategyRecord {
+            actions: self.valid_actions.iter().map(|a| a.assignments.clone()).collect(),
+            average_strategy: self.player_regret.to_average_strategy(),
+            regret_sum: self.player_regret.regret_sum(),
+        }
+    }
+
+    /// Writes the solved strategy to `writer` as JSON for external visualizers.
+    pub fn dump_json<W: Write>(&self, writer: W) -> serde_json::Result<()> {
+        serde_json::to_writer_pretty(writer, &self.to_record())
+    }
+
     pub fn print_avg_strategy(&self) {
         let avg_strategy = self.player_regret.to_average_strategy();
         let mut s = "Avg-Strategy [\n".to_string();
@@ -114,6 +217,14 @@ impl Trainer {
     }
 }
 
+/// The solved Colonel Blotto strategy, emitted to JSON.
+#[derive(Serialize)]
+pub struct StrategyRecord {
+    pub actions: Vec<Vec<u32>>,
+    pub average_strategy: Vec<f64>,
+    pub regret_sum: Vec<i64>,
+}
+
 pub struct Regret {
     regrets: Vec<i64>,
     strategy_sum: Vec<i64>,
@@ -149,6 +260,11 @@ impl Regret {
         }
     }
 
+    /// The cumulative regret accumulated per action so far.
+    pub fn regret_sum(&self) -> Vec<i64> {
+        self.regrets.clone()
+    }
+
     pub fn to_average_strategy(&self) -> Vec<f64> {
         let strategy_sum_total: f64 = self.strategy_sum.iter().sum::<i64>() as f64;
         if strategy_sum_total <= 0.0 {
@@ -176,28 +292,12 @@ impl Regret {
         my_action: &Action,
         opponent_action: &Action,
         valid_actions: &[Action],
+        payoff_config: &PayoffConfig,
     ) {
-        let payoff = calc_payoff(my_action, opponent_action);
+        let payoff = payoff_config.payoff(my_action, opponent_action);
         for action in valid_actions {
-            let diff = calc_payoff(action, opponent_action) - payoff;
+            let diff = payoff_config.payoff(action, opponent_action) - payoff;
             self.regrets[action.index] += diff;
         }
     }
 }
-
-pub fn calc_payoff(a: &Action, b: &Action) -> i64 {
-    assert!(a.assignments.len() == b.assignments.len());
-    let mut claimed = 0;
-    for i in 0..a.assignments.len() {
-        claimed += match a.assignments[i].cmp(&b.assignments[i]) {
-            Ordering::Less => -1,
-            Ordering::Greater => 1,
-            Ordering::Equal => 0,
-        };
-    }
-    match claimed.cmp(&0) {
-        Ordering::Less => -1,
-        Ordering::Equal => 0,
-        Ordering::Greater => 1,
-    }
-}
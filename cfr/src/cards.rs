@@ -0,0 +1,189 @@
+//! A compact, reusable playing-card subsystem.
+//!
+//! A [`Card`] is a single byte using the standard dense encoding
+//! `rank_index * 4 + suit_index`, so the 52 ordinary cards occupy indices
+//! `0..52` and decode in O(1): `rank = byte >> 2`, `suit = byte & 3`. Jokers
+//! live just past the ordinary range so a deck can optionally carry them
+//! without perturbing the packed indices the rest of the cards rely on.
+//!
+//! Games that only need ranks (like Kuhn) or the full 52/54-card deck (like
+//! Texas Hold'em dealing) can build on this instead of re-implementing deck
+//! plumbing.
+
+use rand::{
+    seq::SliceRandom,
+    Rng,
+};
+
+/// Number of ranks in a suit (Two through Ace).
+pub const RANK_COUNT: u8 = 13;
+/// Number of suits.
+pub const SUIT_COUNT: u8 = 4;
+/// Number of ordinary (non-joker) cards in a deck.
+pub const CARD_COUNT: u8 = RANK_COUNT * SUIT_COUNT;
+/// Number of jokers carried by a deck built with [`WithOrWithoutJokers::WithJokers`].
+pub const JOKER_COUNT: u8 = 2;
+
+/// Selects whether [`deck`] includes the two jokers.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum WithOrWithoutJokers {
+    WithoutJokers,
+    WithJokers,
+}
+
+/// Builds a fresh, unshuffled deck with or without the two jokers — the shared
+/// entry point games should reach for instead of hand-rolling a card list.
+pub fn deck(jokers: WithOrWithoutJokers) -> Deck {
+    match jokers {
+        WithOrWithoutJokers::WithoutJokers => Deck::standard(),
+        WithOrWithoutJokers::WithJokers => Deck::standard_with_jokers(),
+    }
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Suit {
+    Clubs,
+    Diamonds,
+    Hearts,
+    Spades,
+}
+
+impl Suit {
+    pub const ALL: [Suit; 4] = [Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades];
+
+    pub fn from_index(index: u8) -> Suit {
+        Suit::ALL[index as usize]
+    }
+
+    pub fn index(self) -> u8 {
+        self as u8
+    }
+
+    pub fn to_char(self) -> char {
+        match self {
+            Suit::Clubs => 'c',
+            Suit::Diamonds => 'd',
+            Suit::Hearts => 'h',
+            Suit::Spades => 's',
+        }
+    }
+}
+
+/// A single card packed into one byte. Ordinary cards use indices `0..52`
+/// (`rank_index * 4 + suit_index`); jokers use `52` and `53`.
+#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct Card(u8);
+
+impl Card {
+    /// The two joker cards, available in decks built with
+    /// [`Deck::standard_with_jokers`].
+    pub const JOKERS: [Card; 2] = [Card(CARD_COUNT), Card(CARD_COUNT + 1)];
+
+    /// Builds an ordinary card from a `0..13` rank index and a suit.
+    pub fn new(rank: u8, suit: Suit) -> Card {
+        debug_assert!(rank < RANK_COUNT);
+        Card(rank * SUIT_COUNT + suit.index())
+    }
+
+    /// Builds a card from its packed index. Indices `0..52` are ordinary cards;
+    /// `52`/`53` are jokers.
+    pub fn from_index(index: u8) -> Card {
+        debug_assert!(index < CARD_COUNT + 2);
+        Card(index)
+    }
+
+    /// The rank index in `0..13`, where `0` is a Two and `12` an Ace. Meaningful
+    /// only for ordinary cards.
+    pub fn rank(self) -> u8 {
+        debug_assert!(!self.is_joker());
+        self.0 >> 2
+    }
+
+    /// The suit. Meaningful only for ordinary cards.
+    pub fn suit(self) -> Suit {
+        debug_assert!(!self.is_joker());
+        Suit::from_index(self.0 & 3)
+    }
+
+    /// Whether this is a Jack, Queen or King.
+    pub fn is_face(self) -> bool {
+        !self.is_joker() && matches!(self.rank(), 9..=11)
+    }
+
+    pub fn is_joker(self) -> bool {
+        self.0 >= CARD_COUNT
+    }
+
+    /// The packed index, suitable for array-indexed lookups.
+    pub fn to_index(self) -> usize {
+        self.0 as usize
+    }
+
+    fn rank_char(self) -> char {
+        match self.rank() {
+            8 => 'T',
+            9 => 'J',
+            10 => 'Q',
+            11 => 'K',
+            12 => 'A',
+            r => (b'2' + r) as char,
+        }
+    }
+}
+
+impl std::fmt::Display for Card {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_joker() {
+            write!(f, "Jk")
+        } else {
+            write!(f, "{}{}", self.rank_char(), self.suit().to_char())
+        }
+    }
+}
+
+impl std::fmt::Debug for Card {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+/// A mutable stack of cards dealt from the top.
+#[derive(Debug, Clone)]
+pub struct Deck {
+    cards: Vec<Card>,
+}
+
+impl Deck {
+    /// The 52 ordinary cards in packed-index order.
+    pub fn standard() -> Deck {
+        Deck {
+            cards: (0..CARD_COUNT).map(Card::from_index).collect(),
+        }
+    }
+
+    /// The 52 ordinary cards plus the two jokers.
+    pub fn standard_with_jokers() -> Deck {
+        let mut deck = Deck::standard();
+        deck.cards.extend_from_slice(&Card::JOKERS);
+        deck
+    }
+
+    pub fn shuffle(&mut self, rng: &mut impl Rng) {
+        self.cards.shuffle(rng);
+    }
+
+    /// Removes and returns the top `n` cards. Panics if fewer than `n` remain.
+    pub fn deal(&mut self, n: usize) -> Vec<Card> {
+        assert!(n <= self.cards.len(), "cannot deal {} cards from {}", n, self.cards.len());
+        self.cards.split_off(self.cards.len() - n)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+}
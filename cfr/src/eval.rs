@@ -1,8 +1,23 @@
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    io::{
+        self,
+        Write,
+    },
+};
 
 use itertools::Itertools;
 use log::debug;
 use more_asserts::assert_ge;
+use rand::Rng;
+use rand_distr::{
+    Distribution,
+    WeightedIndex,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
 
 use crate::games::{
     Game,
@@ -231,6 +246,191 @@ pub fn calc_best_response_value<G: Game, St: Strategy<G>>(
     node_util
 }
 
+/// A pruning variant of [`calc_best_response_value`].
+///
+/// It computes the identical reach-probability-weighted best-response value but
+/// skips subtrees that provably cannot change the result: chance outcomes with
+/// zero probability, opponent actions played with zero mass, and sibling states
+/// an info set is reached through with zero probability. `visited` counts the
+/// nodes actually traversed, so callers can measure how much the skipping saved.
+/// The returned value is exact; the skipping only helps when the strategy is not
+/// full-support (a full-support strategy reaches every node, so no branch is
+/// pruned and the traversal matches the exact variant).
+pub fn calc_best_response_value_pruned<G: Game, St: Strategy<G>>(
+    action_utilities: &mut HashMap<G::InfoSet, Vec<f64>>,
+    reach_probabilities: &HashMap<G::InfoSet, ReachProbabilities<G>>,
+    br_player: PlayerId,
+    strategy: &St,
+    game: &G,
+    state: &G::State,
+    visited: &mut usize,
+) -> f64 {
+    *visited += 1;
+    if game.is_terminal(state) {
+        return game.get_payouts(state)[br_player.index()];
+    }
+
+    if game.get_node_player_id(state) == PlayerId::Chance {
+        let actions = game.list_legal_chance_actions(state);
+        let mut node_util = 0.0;
+        for (act, prob) in actions {
+            // A zero-probability chance outcome cannot move the expectation.
+            if prob == 0.0 {
+                continue;
+            }
+            let next_state = game.with_action(state, act);
+            let act_util = calc_best_response_value_pruned(
+                action_utilities,
+                reach_probabilities,
+                br_player,
+                strategy,
+                game,
+                &next_state,
+                visited,
+            );
+            node_util += prob * act_util;
+        }
+        return node_util;
+    }
+
+    let actions = game.list_legal_actions(state);
+    if game.get_node_player_id(state) == br_player {
+        let info_set = game.to_info_set(state);
+
+        if !action_utilities.contains_key(&info_set) {
+            let mut act_utils = vec![0.0; actions.len()];
+            for (act_i, act) in actions.iter().enumerate() {
+                let rp = reach_probabilities.get(&info_set).unwrap();
+                let mut act_util = 0.0;
+                for (sib_state, state_reach_prob) in rp.reach_probabilities.iter() {
+                    // Sibling states reached with zero probability add nothing.
+                    if *state_reach_prob == 0.0 {
+                        continue;
+                    }
+                    let next_state = game.with_action(sib_state, *act);
+                    let util = calc_best_response_value_pruned(
+                        action_utilities,
+                        reach_probabilities,
+                        br_player,
+                        strategy,
+                        game,
+                        &next_state,
+                        visited,
+                    );
+                    act_util += state_reach_prob * util;
+                }
+                act_utils[act_i] = act_util;
+            }
+            action_utilities.insert(info_set.clone(), act_utils);
+        }
+
+        let best_action_index = max_index(action_utilities.get(&info_set).unwrap());
+        let best_action = actions[best_action_index];
+        let next_state = game.with_action(state, best_action);
+        return calc_best_response_value_pruned(
+            action_utilities,
+            reach_probabilities,
+            br_player,
+            strategy,
+            game,
+            &next_state,
+            visited,
+        );
+    }
+
+    // The opponent plays the trained strategy; skip zero-mass actions.
+    let info_set = game.to_info_set(state);
+    let strategy_ary = strategy.safe_get_strategy(actions.len(), &info_set);
+    let mut node_util = 0.0;
+    for (i, act) in actions.iter().enumerate() {
+        let act_prob = strategy_ary[i];
+        if act_prob == 0.0 {
+            continue;
+        }
+        let next_state = game.with_action(state, *act);
+        let util = calc_best_response_value_pruned(
+            action_utilities,
+            reach_probabilities,
+            br_player,
+            strategy,
+            game,
+            &next_state,
+            visited,
+        );
+        node_util += act_prob * util;
+    }
+    node_util
+}
+
+/// Exploitability computed through the pruned best-response traversal
+/// ([`calc_best_response_value_pruned`]). Returns the identical value as
+/// [`compute_exploitability`], visiting fewer nodes the more zero-mass branches
+/// the strategy leaves unreached; the number of nodes touched by each
+/// best-response pass is logged at `debug` level.
+pub fn compute_exploitability_pruned<G: Game, St: Strategy<G>>(game: &G, strategy: &St) -> f64 {
+    let root_state = game.new_root();
+    let mut rp0: HashMap<G::InfoSet, ReachProbabilities<G>> = HashMap::new();
+    let mut rp1: HashMap<G::InfoSet, ReachProbabilities<G>> = HashMap::new();
+    calc_reach_probabilities(PlayerId::Player(0), strategy, game, &root_state, 1.0, &mut rp0);
+    calc_reach_probabilities(PlayerId::Player(1), strategy, game, &root_state, 1.0, &mut rp1);
+    let mut brmap0: HashMap<G::InfoSet, Vec<f64>> = HashMap::new();
+    let mut brmap1: HashMap<G::InfoSet, Vec<f64>> = HashMap::new();
+    let mut visited0 = 0;
+    let mut visited1 = 0;
+    let br0 = calc_best_response_value_pruned(
+        &mut brmap0,
+        &rp0,
+        PlayerId::Player(0),
+        strategy,
+        game,
+        &root_state,
+        &mut visited0,
+    );
+    let br1 = calc_best_response_value_pruned(
+        &mut brmap1,
+        &rp1,
+        PlayerId::Player(1),
+        strategy,
+        game,
+        &root_state,
+        &mut visited1,
+    );
+    debug!("pruned BR visited {} / {} nodes", visited0, visited1);
+
+    // Info sets reached only through skipped zero-mass branches have no
+    // utilities yet, but the expected-value passes below walk every branch and
+    // look each one up. They are reached with zero probability, so any action
+    // choice leaves the value unchanged.
+    fn fill_unreached<G: Game>(
+        game: &G,
+        rp: &HashMap<G::InfoSet, ReachProbabilities<G>>,
+        brmap: &mut HashMap<G::InfoSet, Vec<f64>>,
+    ) {
+        for (info_set, states) in rp.iter() {
+            brmap.entry(info_set.clone()).or_insert_with(|| {
+                let state = states.reach_probabilities.keys().next().unwrap();
+                vec![0.0; game.list_legal_actions(state).len()]
+            });
+        }
+    }
+    fill_unreached(game, &rp0, &mut brmap0);
+    fill_unreached(game, &rp1, &mut brmap1);
+
+    let br_pure_strategies0 = best_response_utils_to_pure_strategy::<G>(&brmap0);
+    let br_pure_strategies1 = best_response_utils_to_pure_strategy::<G>(&brmap1);
+    let root_state = game.new_root();
+    let ev_0 =
+        calc_expected_value(PlayerId::Player(1), strategy, &br_pure_strategies1, game, &root_state);
+    let ev_1 =
+        calc_expected_value(PlayerId::Player(0), &br_pure_strategies0, strategy, game, &root_state);
+    debug!("pruned util_1(s0, s_br1): {} util_0(s_br0, s1): {}", ev_0, ev_1);
+    debug!("pruned br0: {}, br1: {}", br0, br1);
+
+    let exploitability = (ev_0 + ev_1) / 2.0;
+    assert_ge!(exploitability, 0.0, "Exploitability must be positive value.");
+    exploitability
+}
+
 pub fn calc_expected_value<G, S0, S1>(
     player_id: PlayerId,
     strategy0: &S0,
@@ -348,3 +548,331 @@ pub fn compute_exploitability<G: Game, St: Strategy<G>>(game: &G, strategy: &St)
     assert_ge!(exploitability, 0.0, "Exploitability must be positive value.");
     exploitability
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::games::{
+        kuhn::Kuhn,
+        leduc::Leduc,
+    };
+
+    /// Builds a deterministic strategy that puts all mass on the first legal
+    /// action at every info set, so the opponent traversal has zero-probability
+    /// branches for the pruned best response to skip.
+    fn first_action_strategy<G: Game>(
+        game: &G,
+        state: &G::State,
+        out: &mut HashMap<G::InfoSet, Vec<f64>>,
+    ) {
+        if game.is_terminal(state) {
+            return;
+        }
+        if game.get_node_player_id(state) == PlayerId::Chance {
+            for (act, _prob) in game.list_legal_chance_actions(state) {
+                first_action_strategy(game, &game.with_action(state, act), out);
+            }
+            return;
+        }
+        let actions = game.list_legal_actions(state);
+        let info_set = game.to_info_set(state);
+        out.entry(info_set).or_insert_with(|| {
+            let mut s = vec![0.0; actions.len()];
+            s[0] = 1.0;
+            s
+        });
+        for act in actions {
+            first_action_strategy(game, &game.with_action(state, act), out);
+        }
+    }
+
+    fn assert_pruned_matches<G: Game>(game: &G) {
+        let mut strategy: HashMap<G::InfoSet, Vec<f64>> = HashMap::new();
+        first_action_strategy(game, &game.new_root(), &mut strategy);
+        let exact = compute_exploitability(game, &strategy);
+        let pruned = compute_exploitability_pruned(game, &strategy);
+        assert!(
+            (exact - pruned).abs() < 1e-9,
+            "pruned exploitability {} != exact {}",
+            pruned,
+            exact
+        );
+    }
+
+    #[test]
+    fn pruned_matches_exact_kuhn() {
+        assert_pruned_matches(&Kuhn::new());
+    }
+
+    #[test]
+    fn pruned_matches_exact_leduc() {
+        assert_pruned_matches(&Leduc::new());
+    }
+}
+
+/// One node visited during a recorded playout.
+///
+/// Info sets and actions are rendered through their `Display` impls so the
+/// schema does not depend on `G::InfoSet`/`G::Action` being `Serialize`;
+/// external viewers key on the same `info_set` string the logs print.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TraceStep {
+    /// The acting player, e.g. `"Player(0)"` or `"Chance"`.
+    pub player: String,
+    /// The info set string at a player node; `None` at a chance node.
+    pub info_set: Option<String>,
+    pub legal_actions: Vec<String>,
+    /// The acting strategy's distribution over `legal_actions` at a player
+    /// node; `None` at a chance node.
+    pub strategy: Option<Vec<f64>>,
+    /// The action actually taken (sampled at both player and chance nodes).
+    pub sampled_action: String,
+    pub is_chance: bool,
+}
+
+/// A full sampled playout between two strategies, terminating with the payouts.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameTrace {
+    pub steps: Vec<TraceStep>,
+    pub payouts: Vec<f64>,
+}
+
+/// Plays a single hand between `strategy0` (seat 0) and `strategy1` (seat 1),
+/// sampling every player action from `safe_get_strategy` and every chance
+/// outcome from its weighted distribution, and records each visited node into a
+/// serde-serializable [`GameTrace`]. This surfaces exactly where a strategy
+/// deviates from a best response without scraping the `debug!` logs.
+pub fn play_and_record<G, S0, S1, R>(
+    strategy0: &S0,
+    strategy1: &S1,
+    game: &G,
+    rng: &mut R,
+) -> GameTrace
+where
+    G: Game,
+    S0: Strategy<G>,
+    S1: Strategy<G>,
+    R: Rng,
+{
+    let mut state = game.new_root();
+    let mut steps = Vec::new();
+    while !game.is_terminal(&state) {
+        let player = game.get_node_player_id(&state);
+        if player == PlayerId::Chance {
+            let actions = game.list_legal_chance_actions(&state);
+            let dist = WeightedIndex::new(actions.iter().map(|a| a.1)).unwrap();
+            let index = dist.sample(rng);
+            let (act, _prob) = actions[index];
+            steps.push(TraceStep {
+                player: format!("{:?}", player),
+                info_set: None,
+                legal_actions: actions.iter().map(|a| a.0.to_string()).collect(),
+                strategy: None,
+                sampled_action: act.to_string(),
+                is_chance: true,
+            });
+            state = game.with_action(&state, act);
+            continue;
+        }
+
+        let actions = game.list_legal_actions(&state);
+        let info_set = game.to_info_set(&state);
+        let strategy = match player {
+            PlayerId::Player(0) => strategy0.safe_get_strategy(actions.len(), &info_set),
+            PlayerId::Player(1) => strategy1.safe_get_strategy(actions.len(), &info_set),
+            PlayerId::Player(_) => panic!("play_and_record only supports two seats"),
+            PlayerId::Chance => unreachable!(),
+        };
+        let dist = WeightedIndex::new(&strategy).unwrap();
+        let index = dist.sample(rng);
+        let act = actions[index];
+        steps.push(TraceStep {
+            player: format!("{:?}", player),
+            info_set: Some(info_set.to_string()),
+            legal_actions: actions.iter().map(|a| a.to_string()).collect(),
+            strategy: Some(strategy),
+            sampled_action: act.to_string(),
+            is_chance: false,
+        });
+        state = game.with_action(&state, act);
+    }
+    GameTrace {
+        steps,
+        payouts: game.get_payouts(&state),
+    }
+}
+
+/// Streams many [`GameTrace`]s to a writer as newline-delimited JSON (one trace
+/// per line), so long evaluation runs can be appended incrementally and read
+/// back trace-by-trace.
+pub struct TraceWriter<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> TraceWriter<W> {
+    pub fn new(writer: W) -> TraceWriter<W> {
+        TraceWriter {
+            writer,
+        }
+    }
+
+    pub fn write_trace(&mut self, trace: &GameTrace) -> io::Result<()> {
+        let line = serde_json::to_string(trace)?;
+        self.writer.write_all(line.as_bytes())?;
+        self.writer.write_all(b"\n")
+    }
+
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+/// A conservative lower bound on exploitability via Local Best Response (LBR).
+///
+/// Unlike [`compute_exploitability`], which needs two full-tree traversals and
+/// is intractable for large games, LBR walks the tree once per player while
+/// maintaining a *belief*: a weighted set of the opponent's worlds (full states)
+/// consistent with the observations so far. At each LBR-player decision it
+/// scores every candidate action by applying it in every believed world and
+/// assuming both players then follow the cheap fixed `rollout_policy` to a
+/// terminal (the belief-weighted expected payoff), and plays the max-value
+/// action. When the opponent acts, the belief is reweighted by
+/// `strategy.safe_get_strategy`'s probability of the observed action in each
+/// world's info set and renormalized.
+///
+/// Because every evaluated action is scored against the same `rollout_policy`
+/// continuation, if the chosen action matches what the rollout policy would do
+/// the value reduces to the rollout value — so LBR never over-estimates, and
+/// the summed per-player values halved are a conservative exploitability
+/// estimate. Chance outcomes are folded into the belief rather than split on a
+/// public projection, which only makes the bound looser (never tighter).
+pub fn compute_local_best_response<G, St, Rp>(
+    game: &G,
+    strategy: &St,
+    rollout_policy: &Rp,
+) -> f64
+where
+    G: Game,
+    St: Strategy<G>,
+    Rp: Strategy<G>,
+{
+    let lbr0 = lbr_player_value(game, strategy, rollout_policy, PlayerId::Player(0));
+    let lbr1 = lbr_player_value(game, strategy, rollout_policy, PlayerId::Player(1));
+    debug!("LBR values: p0 {}, p1 {}", lbr0, lbr1);
+    (lbr0 + lbr1) / 2.0
+}
+
+/// The LBR value for a single `lbr_player`, starting from a unit belief at the
+/// root.
+fn lbr_player_value<G, St, Rp>(
+    game: &G,
+    strategy: &St,
+    rollout_policy: &Rp,
+    lbr_player: PlayerId,
+) -> f64
+where
+    G: Game,
+    St: Strategy<G>,
+    Rp: Strategy<G>,
+{
+    let belief = vec![(game.new_root(), 1.0)];
+    lbr_recurse(game, strategy, rollout_policy, lbr_player, &belief)
+}
+
+fn belief_mass<G: Game>(belief: &[(G::State, f64)]) -> f64 {
+    belief.iter().map(|(_, w)| *w).sum()
+}
+
+fn lbr_recurse<G, St, Rp>(
+    game: &G,
+    strategy: &St,
+    rollout_policy: &Rp,
+    lbr_player: PlayerId,
+    belief: &[(G::State, f64)],
+) -> f64
+where
+    G: Game,
+    St: Strategy<G>,
+    Rp: Strategy<G>,
+{
+    let total = belief_mass::<G>(belief);
+    if total == 0.0 {
+        return 0.0;
+    }
+    let representative = &belief[0].0;
+
+    if game.is_terminal(representative) {
+        // Belief-weighted terminal payoff to the LBR player.
+        let value: f64 = belief
+            .iter()
+            .map(|(state, w)| w * game.get_payouts(state)[lbr_player.index()])
+            .sum();
+        return value / total;
+    }
+
+    let player = game.get_node_player_id(representative);
+    if player == PlayerId::Chance {
+        // Fold every chance outcome into the belief and continue.
+        let mut next_belief = Vec::new();
+        for (state, w) in belief {
+            for (act, prob) in game.list_legal_chance_actions(state) {
+                next_belief.push((game.with_action(state, act), w * prob));
+            }
+        }
+        return lbr_recurse(game, strategy, rollout_policy, lbr_player, &next_belief);
+    }
+
+    let actions = game.list_legal_actions(representative);
+    if player == lbr_player {
+        // Score each action by a rollout from every believed world, pick the
+        // best, then continue the walk having played it.
+        let mut best_index = 0;
+        let mut best_value = f64::NEG_INFINITY;
+        for (i, act) in actions.iter().enumerate() {
+            let mut value = 0.0;
+            for (state, w) in belief {
+                let next_state = game.with_action(state, *act);
+                value += w
+                    * calc_expected_value(
+                        lbr_player,
+                        rollout_policy,
+                        rollout_policy,
+                        game,
+                        &next_state,
+                    );
+            }
+            if value > best_value {
+                best_value = value;
+                best_index = i;
+            }
+        }
+        let best_action = actions[best_index];
+        let next_belief: Vec<(G::State, f64)> =
+            belief.iter().map(|(s, w)| (game.with_action(s, best_action), *w)).collect();
+        return lbr_recurse(game, strategy, rollout_policy, lbr_player, &next_belief);
+    }
+
+    // Opponent node: take the expectation over the opponent's trained strategy,
+    // updating the belief by each action's probability in each world.
+    let mut node_value = 0.0;
+    for (i, act) in actions.iter().enumerate() {
+        let mut next_belief = Vec::with_capacity(belief.len());
+        let mut action_mass = 0.0;
+        for (state, w) in belief {
+            let info_set = game.to_info_set(state);
+            let probs = strategy.safe_get_strategy(actions.len(), &info_set);
+            let weight = w * probs[i];
+            if weight == 0.0 {
+                continue;
+            }
+            action_mass += weight;
+            next_belief.push((game.with_action(state, *act), weight));
+        }
+        if action_mass == 0.0 {
+            continue;
+        }
+        let subtree = lbr_recurse(game, strategy, rollout_policy, lbr_player, &next_belief);
+        node_value += (action_mass / total) * subtree;
+    }
+    node_value
+}
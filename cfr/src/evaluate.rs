@@ -0,0 +1,203 @@
+//! Head-to-head evaluation of a blueprint against pluggable agents.
+//!
+//! Where [`compute_exploitability`](crate::eval::compute_exploitability) scores
+//! a strategy against a worst-case opponent, this module measures realized
+//! performance against concrete, swappable opponents. An [`Agent`] picks an
+//! action from the legal set at each of its decision points, and [`evaluate`]
+//! plays two agents against each other over many seeded games, reporting the
+//! same [`SeatStats`] (mean payoff, standard error, 95% CI, win rate) the match
+//! simulator uses.
+
+use std::collections::HashMap;
+
+use rand::Rng;
+use rand_distr::{
+    Distribution,
+    WeightedIndex,
+};
+
+use crate::{
+    games::{
+        dudo::{
+            Claim,
+            DudoAction,
+            DudoInfoSet,
+            DudoState,
+        },
+        GameState,
+        PlayerId,
+    },
+    sim::SeatStats,
+};
+
+/// A player that chooses an action given the current info set and the legal
+/// actions available at it.
+pub trait Agent<S: GameState> {
+    fn choose_action(&mut self, info_set: &S::InfoSet, legal_actions: &[S::Action]) -> S::Action;
+}
+
+/// Plays a trained blueprint: samples each move from the stored average-strategy
+/// distribution, falling back to uniform when an info set was never visited
+/// during training.
+pub struct BlueprintAgent<S: GameState, R: Rng> {
+    blueprint: HashMap<S::InfoSet, Vec<f64>>,
+    rng: R,
+}
+
+impl<S: GameState, R: Rng> BlueprintAgent<S, R> {
+    pub fn new(blueprint: HashMap<S::InfoSet, Vec<f64>>, rng: R) -> Self {
+        BlueprintAgent {
+            blueprint,
+            rng,
+        }
+    }
+}
+
+impl<S: GameState, R: Rng> Agent<S> for BlueprintAgent<S, R> {
+    fn choose_action(&mut self, info_set: &S::InfoSet, legal_actions: &[S::Action]) -> S::Action {
+        let probs = self.blueprint.get(info_set).cloned().unwrap_or_else(|| {
+            vec![1.0 / legal_actions.len() as f64; legal_actions.len()]
+        });
+        let dist = WeightedIndex::new(&probs).unwrap();
+        legal_actions[dist.sample(&mut self.rng)]
+    }
+}
+
+/// Picks uniformly at random among the legal actions.
+pub struct UniformRandomAgent<R: Rng> {
+    rng: R,
+}
+
+impl<R: Rng> UniformRandomAgent<R> {
+    pub fn new(rng: R) -> Self {
+        UniformRandomAgent {
+            rng,
+        }
+    }
+}
+
+impl<S: GameState, R: Rng> Agent<S> for UniformRandomAgent<R> {
+    fn choose_action(&mut self, _info_set: &S::InfoSet, legal_actions: &[S::Action]) -> S::Action {
+        legal_actions[self.rng.gen_range(0..legal_actions.len())]
+    }
+}
+
+/// A greedy Dudo baseline analogous to the minimax "brutus" agent: it calls
+/// `Dudo` as soon as the outstanding claim's count exceeds the statistical
+/// expectation across all dice, and otherwise makes the smallest legal raise.
+pub struct DudoChallengeAgent;
+
+impl Agent<DudoState> for DudoChallengeAgent {
+    fn choose_action(
+        &mut self,
+        info_set: &DudoInfoSet,
+        legal_actions: &[DudoAction],
+    ) -> DudoAction {
+        let total_dice: i32 = info_set.dice_count.iter().sum();
+        let last_claim: Option<Claim> =
+            info_set.action_history.iter().rev().find_map(|a| match a {
+                DudoAction::Claim(c) => Some(*c),
+                _ => None,
+            });
+
+        // A die shows a given face, or a wild one, with probability 1/3, so the
+        // expected number of matching dice is total_dice / 3. Claims on ones,
+        // and any claim in a palifico round (ones not wild), match with
+        // probability 1/6. Challenge any claim above the expectation.
+        if legal_actions.contains(&DudoAction::Dudo) {
+            if let Some(claim) = last_claim {
+                let match_prob = if claim.rank == 0 || info_set.palifico {
+                    1.0 / 6.0
+                } else {
+                    1.0 / 3.0
+                };
+                if claim.count as f64 > total_dice as f64 * match_prob {
+                    return DudoAction::Dudo;
+                }
+            }
+        }
+
+        // `list_legal_actions` does not list the claims in ascending order (claims
+        // on ones come before smaller claims on other faces), so take the
+        // smallest claim by `Claim`'s ordering.
+        legal_actions
+            .iter()
+            .filter(|a| !matches!(a, DudoAction::Dudo))
+            .min()
+            .cloned()
+            .unwrap_or(DudoAction::Dudo)
+    }
+}
+
+fn play_one<S, R>(
+    agent0: &mut dyn Agent<S>,
+    agent1: &mut dyn Agent<S>,
+    rng: &mut R,
+) -> [f64; 2]
+where
+    S: GameState,
+    R: Rng,
+{
+    let mut state = S::new_root();
+    while !state.is_terminal() {
+        let player = state.get_node_player_id();
+        if player == PlayerId::Chance {
+            let actions = state.list_legal_chance_actions();
+            let dist = WeightedIndex::new(actions.iter().map(|(_, p)| *p)).unwrap();
+            let action = actions[dist.sample(rng)].0;
+            state = state.with_action(action);
+            continue;
+        }
+        let info_set = state.to_info_set();
+        let legal_actions = state.list_legal_actions();
+        let agent: &mut dyn Agent<S> = match player.index() {
+            0 => agent0,
+            _ => agent1,
+        };
+        let action = agent.choose_action(&info_set, &legal_actions);
+        state = state.with_action(action);
+    }
+    state.get_payouts()
+}
+
+/// Plays `num_games` games of `S` between `agent0` (seat 0) and `agent1`
+/// (seat 1) with a seeded RNG, returning the per-seat statistics.
+pub fn evaluate<S, R>(
+    agent0: &mut dyn Agent<S>,
+    agent1: &mut dyn Agent<S>,
+    num_games: usize,
+    rng: &mut R,
+) -> Vec<SeatStats>
+where
+    S: GameState,
+    R: Rng,
+{
+    // Online mean/variance via Welford's algorithm, matching `sim::simulate`.
+    let mut mean = [0.0f64; 2];
+    let mut m2 = [0.0f64; 2];
+    let mut wins = [0usize; 2];
+    for g in 0..num_games {
+        let payouts = play_one(agent0, agent1, rng);
+        let n = (g + 1) as f64;
+        for p in 0..2 {
+            let delta = payouts[p] - mean[p];
+            mean[p] += delta / n;
+            m2[p] += delta * (payouts[p] - mean[p]);
+            if payouts[p] > 0.0 {
+                wins[p] += 1;
+            }
+        }
+    }
+    (0..2)
+        .map(|p| SeatStats {
+            games: num_games,
+            mean_payoff: mean[p],
+            variance: if num_games > 1 {
+                m2[p] / (num_games as f64 - 1.0)
+            } else {
+                0.0
+            },
+            wins: wins[p],
+        })
+        .collect()
+}
@@ -4,9 +4,11 @@ use rand_distr::{
     WeightedIndex,
 };
 
+pub mod coinche;
 pub mod dudo;
 pub mod kuhn;
 pub mod leduc;
+pub mod rps;
 
 // TODO: Make it something like
 // ```
@@ -18,6 +20,7 @@ pub mod leduc;
 // ```
 // So that we can use raw PlayerId where there is no chance to have ChanceNode.
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(serde::Serialize, serde::Deserialize)]
 pub enum PlayerId {
     Chance,
     Player(usize),
@@ -31,11 +34,12 @@ impl PlayerId {
         }
     }
 
-    pub fn opponent(&self) -> PlayerId {
+    /// The seat that acts after this one in a `player_count`-seat rotation.
+    /// Replaces the old heads-up-only `opponent()`; for a two-player game
+    /// `next_player(2)` is still the single opponent.
+    pub fn next_player(&self, player_count: usize) -> PlayerId {
         match self {
-            PlayerId::Player(0) => PlayerId::Player(1),
-            PlayerId::Player(1) => PlayerId::Player(0),
-            PlayerId::Player(_) => todo!("REMOVE this method to support more than 2 players."),
+            PlayerId::Player(i) => PlayerId::Player((i + 1) % player_count),
             PlayerId::Chance => panic!(),
         }
     }
@@ -57,14 +61,24 @@ pub trait Game {
         + std::cmp::Ord;
     type Action: Copy + std::fmt::Display + std::fmt::Debug + std::cmp::Eq + std::hash::Hash;
 
+    /// The number of seated players (excluding the chance player). Seats are
+    /// `PlayerId::Player(0..player_count())`, and payout vectors are sized to it.
+    /// Heads-up games inherit the default of two.
+    fn player_count(&self) -> usize {
+        2
+    }
+
     fn new_root(&self) -> Self::State;
 
     fn to_info_set(&self, state: &Self::State) -> Self::InfoSet;
 
     fn is_terminal(&self, state: &Self::State) -> bool;
 
-    // TODO: Make it vector or scalar (but with an argument player_id)
-    fn get_payouts(&self, state: &Self::State) -> [f64; 2];
+    /// The terminal payout to every seat, indexed by `PlayerId::index`. The
+    /// returned vector is always `player_count()` long; two-player zero-sum games
+    /// return `[p, -p]`, but general-sum and multi-seat games are free to return
+    /// any per-seat utilities.
+    fn get_payouts(&self, state: &Self::State) -> Vec<f64>;
 
     fn get_node_player_id(&self, state: &Self::State) -> PlayerId;
 
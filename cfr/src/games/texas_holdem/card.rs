@@ -65,6 +65,10 @@ pub const SUITS: [Suit; 4] = [Suit::Spade, Suit::Club, Suit::Heart, Suit::Diamon
 pub const RANKS: std::ops::RangeInclusive<Rank> = 2..=14;
 pub const RANK_COUNT: usize = 13;
 
+/// Sentinel rank carried by jokers in decks that include them. It sits outside
+/// the playable `2..=14` range so it never collides with a real card rank.
+pub const JOKER_RANK: Rank = 0;
+
 pub fn suit_ch(s: Suit) -> char {
     match s {
         Suit::Spade => 's',
@@ -99,37 +103,60 @@ pub fn parse_cards(s: &str) -> Vec<Card> {
     cards
 }
 
+pub fn suit_to_index(s: Suit) -> usize {
+    SUITS.iter().position(|x| *x == s).unwrap()
+}
+
 pub fn list_all_cards() -> Vec<Card> {
     let mut v = Vec::with_capacity(RANKS.len() * SUITS.len());
     for rank in RANKS {
         for suit in SUITS {
-            v.push(Card {
-                rank,
-                suit,
-            });
+            v.push(Card::new(rank, suit));
         }
     }
     v
 }
 
+/// A single card packed into one byte.
+///
+/// The byte is `rank_index * 4 + suit_index`, where `rank_index = 14 - rank`
+/// (so Ace is `0` and Two is `12`) and `suit_index` is the position in
+/// [`SUITS`]. Ordinary cards therefore occupy a dense `0..52` range, which
+/// makes hole/community collections cheap to hash as byte slices and lets the
+/// `abstraction` module key buckets on packed indices. `rank`/`suit` are
+/// recovered in O(1) via `byte / 4` and `byte % 4`.
 #[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
-pub struct Card {
-    pub rank: Rank,
-    pub suit: Suit,
-}
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct Card(u8);
 
 impl Card {
+    pub fn new(rank: Rank, suit: Suit) -> Card {
+        Card((14 - rank) * 4 + suit_to_index(suit) as u8)
+    }
+
     pub fn dummy() -> Card {
-        Card {
-            rank: 0,
-            suit: Suit::Heart,
-        }
+        // Rank 0 sits outside the `2..=14` range, so it can never collide with
+        // a real card. It decodes back to rank 0 for debugging.
+        Card::new(0, Suit::Heart)
+    }
+
+    pub fn rank(&self) -> Rank {
+        14 - (self.0 / 4)
+    }
+
+    pub fn suit(&self) -> Suit {
+        SUITS[(self.0 % 4) as usize]
+    }
+
+    /// The dense `0..52` packed index, suitable for array-indexed lookups.
+    pub fn to_index(&self) -> usize {
+        self.0 as usize
     }
 
     pub fn str(&self) -> String {
         let mut s = String::with_capacity(2);
-        s.push(rank_ch(self.rank));
-        s.push(suit_ch(self.suit));
+        s.push(rank_ch(self.rank()));
+        s.push(suit_ch(self.suit()));
         s
     }
 }
@@ -163,10 +190,7 @@ impl str::FromStr for Card {
             'd' => Suit::Diamond,
             _ => return Err(format!("Bad suit: {}", s)),
         };
-        Ok(Card {
-            rank,
-            suit,
-        })
+        Ok(Card::new(rank, suit))
     }
 }
 
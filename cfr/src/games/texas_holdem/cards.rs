@@ -3,7 +3,7 @@ use rand::Rng;
 
 use crate::games::texas_holdem::index_to_rank;
 
-use super::{rank_to_index, Card, SUITS};
+use super::{rank_to_index, suit_to_index, Card, SUITS};
 
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Cards {
@@ -107,8 +107,8 @@ impl Cards {
     }
 
     pub fn card_index(card: &Card) -> usize {
-        let r = rank_to_index(card.rank);
-        let s = (card.suit as usize) * 13;
+        let r = rank_to_index(card.rank());
+        let s = (suit_to_index(card.suit())) * 13;
         r + s
     }
 
@@ -118,20 +118,47 @@ impl Cards {
 
         let rank = index_to_rank(index % 13);
         let suit = SUITS[index / 13];
-        Card {
-            rank,
-            suit,
-        }
+        Card::new(rank, suit)
     }
 
     pub fn to_vec(&self) -> Vec<Card> {
-        let mut v = vec![];
-        for i in 0..52 {
-            if self.bit_fields & (1 << i) != 0 {
-                v.push(Self::index_to_card(i));
-            }
+        self.iter().collect()
+    }
+
+    /// Iterates the set cards by scanning the live bit mask with `trailing_zeros`
+    /// and clearing each bit, rather than probing all 52 indices. This is the
+    /// hot path for chance-action enumeration during CFR traversal.
+    pub fn iter(&self) -> CardsIter {
+        CardsIter {
+            remaining: self.bit_fields,
         }
-        v
+    }
+}
+
+/// Iterator over the set cards of a [`Cards`] bitfield (see [`Cards::iter`]).
+pub struct CardsIter {
+    remaining: u64,
+}
+
+impl Iterator for CardsIter {
+    type Item = Card;
+
+    fn next(&mut self) -> Option<Card> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let index = self.remaining.trailing_zeros() as usize;
+        self.remaining &= self.remaining - 1; // clear the lowest set bit
+        Some(Cards::index_to_card(index))
+    }
+}
+
+impl IntoIterator for Cards {
+    type Item = Card;
+    type IntoIter = CardsIter;
+
+    fn into_iter(self) -> CardsIter {
+        self.iter()
     }
 }
 
@@ -1,6 +1,5 @@
 use log::{
     debug,
-    info,
     warn,
 };
 
@@ -20,6 +19,7 @@ pub enum UpdateResult {
 }
 
 #[derive(Debug, PartialEq, Eq)]
+#[derive(serde::Serialize, serde::Deserialize)]
 pub struct HandResult {
     pub won_pots: Vec<i32>,
     pub hands: Vec<HandScore>,
@@ -45,6 +45,7 @@ impl HandResult {
 }
 
 #[derive(Debug)]
+#[derive(serde::Serialize, serde::Deserialize)]
 pub struct GameResult {
     pub scores: Vec<i32>,
 }
@@ -61,6 +62,10 @@ impl Dealer {
         s.players[current as usize].took_action = true;
         s.last_action = Some(act);
         debug!("  Action: {:?}", act);
+        s.events.push(HandEvent::Action {
+            player: current,
+            action: act,
+        });
         match act {
             Action::Fold => {
                 s.players[current as usize].folded = true;
@@ -92,65 +97,138 @@ impl Dealer {
             }
         };
 
-        // TODO: We must skip foled/all-in players.
-        assert_eq!(2, s.players.len());
-        s.next_player = (s.next_player + 1) % self.rule.player_cnt;
+        // Hand the turn to the next seat that can still act, skipping folded and
+        // all-in players. Heads-up keeps its plain alternation (the only other
+        // seat is always the next one).
+        s.next_player = self.next_actionable_player(s, current);
 
         let round_is_finished = s.round_is_finished();
         if s.hand_is_finished(round_is_finished) {
+            // The hand is decided here (showdown on the river, or everyone
+            // folding to one player). Record the outcome that the pot-resolver
+            // will report so the event log is self-contained.
+            let result = self.calculate_won_pots(s);
+            if !s.everyone_folded() {
+                s.events.push(HandEvent::Showdown {
+                    hands: result.hands.clone(),
+                });
+            }
+            s.events.push(HandEvent::PotAwarded {
+                won_pots: result.won_pots,
+            });
             UpdateResult::NextHand
         } else if s.everyone_all_in() {
             UpdateResult::AllIn
         } else if round_is_finished {
             let next_round = s.round.next();
             debug!("* Next Round: {:?} -> {:?}", s.round, next_round);
+            s.events.push(HandEvent::RoundTransition {
+                round: next_round,
+            });
             UpdateResult::NextRound(next_round)
         } else {
             UpdateResult::Keep
         }
     }
 
-    pub fn calculate_won_pots(&self, s: &HandState) -> HandResult {
-        assert!(
-            s.hand_is_finished(s.round_is_finished())
-                || (s.everyone_all_in() && s.community_cards.len() == 5)
-        );
+    /// The actions available to `s.next_player`, for CFR to branch on. `Fold`
+    /// is offered only when the player faces a live bet larger than their own
+    /// contribution; `Call` (which doubles as a check when already matched) is
+    /// always available; and the raises are the concrete `RaiseTo` amounts the
+    /// rule's [`BetAbstraction`] maps to at this node, so the tree stays finite
+    /// and tunable instead of branching on every integer raise.
+    pub fn legal_actions(&self, s: &HandState) -> Vec<Action> {
+        let p = &s.players[s.next_player];
+        let max_bet = s.max_bet();
+
+        let mut v = Vec::with_capacity(2 + self.rule.bet_abstraction.sizes.len());
+        if p.bet < max_bet {
+            v.push(Action::Fold);
+        }
+        v.push(Action::Call);
 
-        let mut scores = Vec::with_capacity(s.players.len());
-        let mut max_score = HandScore::fold();
-        for (i, player) in s.players.iter().enumerate() {
-            let score;
-            if !player.folded {
-                score = hands::calc_player_score(s, player);
-                info!("  score@{}: {}", i, score);
-                max_score = max_score.max(score);
-            } else {
-                score = HandScore::fold();
-                info!("  score@{}: fold", i);
-            }
-            scores.push(score);
+        for raise_to in self.abstracted_raises(s) {
+            v.push(Action::RaiseTo(raise_to));
         }
+        v
+    }
 
-        let mut won_pots = Vec::with_capacity(s.players.len());
-        let mut hands = Vec::with_capacity(s.players.len());
-        let winner_cnt = scores.iter().filter(|&a| *a == max_score).count();
-        let won_amount = s.pot() / winner_cnt as i32;
-        info!("  pot: {}, won: {}, winner_cnt: {}", s.pot(), won_amount, winner_cnt);
-        for (player, score) in s.players.iter().zip(scores.iter()) {
-            let won = if *score == max_score {
-                won_amount - player.bet
-            } else {
-                0 - player.bet
-            };
-            won_pots.push(won);
-            hands.push(*score);
+    /// Translates the rule's [`BetAbstraction`] into concrete `RaiseTo` amounts
+    /// for the current node. Each pot-fraction size raises by that fraction of
+    /// the pot on top of a call; every size is clamped into
+    /// `[min_raise_to, stack]`, de-duplicated, and dropped if it collapses onto
+    /// a call (`<= max_bet`).
+    fn abstracted_raises(&self, s: &HandState) -> Vec<i32> {
+        let p = &s.players[s.next_player];
+        let max_bet = s.max_bet();
+        let min_raise_to = s.round_state.min_raise_to;
+        let stack = p.stack;
+
+        if stack <= max_bet {
+            // No chips beyond a call: the player cannot raise.
+            return vec![];
         }
-        HandResult {
-            won_pots,
-            hands,
+
+        let pot = s.pot();
+        let mut raises: Vec<i32> = self
+            .rule
+            .bet_abstraction
+            .sizes
+            .iter()
+            .map(|size| match size {
+                BetSize::PotFraction(f) => max_bet + (f * pot as f64).round() as i32,
+                BetSize::AllIn => stack,
+            })
+            // Clamp into the legal window. A short stack below `min_raise_to`
+            // collapses to an all-in for the whole stack.
+            .map(|raw| raw.max(min_raise_to).min(stack))
+            .filter(|&raise_to| raise_to > max_bet)
+            .collect();
+
+        raises.sort_unstable();
+        raises.dedup();
+        raises
+    }
+
+    /// Whether `act` is legal for `s.next_player`, so callers can validate a
+    /// move before mutating state instead of relying on `update`'s warn-and-fix
+    /// clamping.
+    pub fn is_legal(&self, s: &HandState, act: Action) -> bool {
+        let p = &s.players[s.next_player];
+        let max_bet = s.max_bet();
+        match act {
+            Action::Fold => p.bet < max_bet,
+            Action::Call => true,
+            Action::RaiseTo(amount) => {
+                let all_in = p.stack;
+                all_in > max_bet
+                    && amount <= all_in
+                    && (amount >= s.round_state.min_raise_to || amount == all_in)
+            }
         }
     }
 
+    /// The next seat clockwise from `from` that can still act — i.e. is neither
+    /// folded nor all-in. If no such seat exists (everyone else is folded or
+    /// all-in) the pointer simply advances one seat and the round-finished
+    /// checks in `update` take over.
+    fn next_actionable_player(&self, s: &HandState, from: usize) -> usize {
+        let n = self.rule.player_cnt;
+        let mut i = from;
+        for _ in 0..n {
+            i = (i + 1) % n;
+            let p = &s.players[i];
+            if !p.is_folded() && !p.is_all_in() {
+                return i;
+            }
+        }
+        (from + 1) % n
+    }
+
+    pub fn calculate_won_pots(&self, s: &HandState) -> HandResult {
+        s.calculate_won_pots()
+    }
+
     pub fn init_round_and_deal_cards(&self, s: &mut HandState, deck: &mut Deck, round: Round) {
         self.init_round(s, round);
         self.deal_cards(s, deck);
@@ -192,9 +270,73 @@ impl Dealer {
     }
 
     pub fn handle_all_in(&self, s: &mut HandState, deck: &mut Deck) -> HandResult {
+        let runouts = self.rule.runouts.max(1);
+        if runouts == 1 {
+            let lack = 5 - s.community_cards.len();
+            s.community_cards.append(&mut deck.draw_n(lack).to_vec());
+            let result = self.calculate_won_pots(s);
+            Self::record_result(s, &result);
+            return result;
+        }
+
+        // Run the board out `runouts` times, each from a fresh clone of the
+        // current deck, and settle each for `1/runouts` of the pot. We
+        // accumulate the gross chips each seat wins across runouts, then divide
+        // by the number of runouts; the `pot`'s worth of chips that integer
+        // division drops is handed out deterministically to the earliest seats
+        // so the payouts still sum to the pot.
+        let player_cnt = s.players.len();
         let lack = 5 - s.community_cards.len();
-        s.community_cards.append(&mut deck.draw_n(lack).to_vec());
-        self.calculate_won_pots(s)
+        let base_board = s.community_cards.clone();
+
+        let mut gross = vec![0i32; player_cnt];
+        let mut hands = vec![HandScore::empty(); player_cnt];
+        for _ in 0..runouts {
+            let mut runout_deck = deck.clone();
+            s.community_cards = base_board.clone();
+            s.community_cards.append(&mut runout_deck.draw_n(lack).to_vec());
+            let result = self.calculate_won_pots(s);
+            for i in 0..player_cnt {
+                // `won_pots` is the net result; the gross chips won back are the
+                // net plus the player's own contribution.
+                gross[i] += result.won_pots[i] + s.players[i].bet;
+            }
+            hands = result.hands;
+        }
+        s.community_cards = base_board;
+
+        let pot = s.pot();
+        let mut won: Vec<i32> = gross.iter().map(|g| g / runouts as i32).collect();
+        let mut remainder = pot - won.iter().sum::<i32>();
+        let contenders: Vec<usize> = (0..player_cnt).filter(|&i| !s.players[i].folded).collect();
+        let mut i = 0;
+        while remainder > 0 && !contenders.is_empty() {
+            won[contenders[i % contenders.len()]] += 1;
+            remainder -= 1;
+            i += 1;
+        }
+
+        let won_pots = (0..player_cnt).map(|i| won[i] - s.players[i].bet).collect();
+        let result = HandResult {
+            won_pots,
+            hands,
+        };
+        Self::record_result(s, &result);
+        result
+    }
+
+    /// Appends the showdown and pot-award events for a resolved `result` onto
+    /// the hand's event log. A showdown event is only emitted when more than one
+    /// player reached it (otherwise everyone folded to the winner).
+    fn record_result(s: &mut HandState, result: &HandResult) {
+        if !s.everyone_folded() {
+            s.events.push(HandEvent::Showdown {
+                hands: result.hands.clone(),
+            });
+        }
+        s.events.push(HandEvent::PotAwarded {
+            won_pots: result.won_pots.clone(),
+        });
     }
 
     pub fn deal_cards(&self, s: &mut HandState, deck: &mut Deck) {
@@ -357,6 +499,25 @@ mod tests {
         assert_eq!(1900, hand_state.players[0].bet);
     }
 
+    #[test]
+    fn test_legal_actions() {
+        let dealer = Dealer::new(Rule::default());
+        let mut hand_state = HandState::default();
+        dealer.init_round(&mut hand_state, Round::Preflop);
+
+        // Player 1 faces the big blind, so may fold, call, min-raise or shove.
+        assert_eq!(1, hand_state.next_player);
+        let acts = dealer.legal_actions(&hand_state);
+        assert!(acts.contains(&Action::Fold));
+        assert!(acts.contains(&Action::Call));
+        assert!(acts.contains(&Action::RaiseTo(hand_state.round_state.min_raise_to)));
+        assert!(acts.contains(&Action::RaiseTo(hand_state.players[1].stack)));
+
+        // A raise below the minimum is illegal unless it is the all-in amount.
+        assert!(!dealer.is_legal(&hand_state, Action::RaiseTo(1)));
+        assert!(dealer.is_legal(&hand_state, Action::RaiseTo(hand_state.players[1].stack)));
+    }
+
     #[test]
     fn test_play() {
         let dealer = Dealer::new(Rule::default());
@@ -377,4 +538,35 @@ mod tests {
         assert_eq!(0, hand_state.next_player);
         assert_eq!(UpdateResult::Keep, dealer.update(&mut hand_state, Action::Call));
     }
+
+    #[test]
+    fn test_hand_event_log() {
+        let dealer = Dealer::new(Rule::default());
+        let mut hand_state = HandState::default();
+        dealer.init_round(&mut hand_state, Round::Preflop);
+
+        // Player 1 folds to Player 0 pre-flop, so the hand ends immediately.
+        assert_eq!(1, hand_state.next_player);
+        assert_eq!(UpdateResult::NextHand, dealer.update(&mut hand_state, Action::Fold));
+
+        // The fold is logged, and since everyone folded there is no showdown,
+        // only the pot award.
+        assert_eq!(
+            vec![
+                HandEvent::Action {
+                    player: 1,
+                    action: Action::Fold,
+                },
+                HandEvent::PotAwarded {
+                    won_pots: hand_state.calculate_won_pots().won_pots,
+                },
+            ],
+            hand_state.events
+        );
+
+        // The log round-trips through JSON.
+        let json = hand_state.events_to_json();
+        let events: Vec<HandEvent> = serde_json::from_str(&json).unwrap();
+        assert_eq!(hand_state.events, events);
+    }
 }
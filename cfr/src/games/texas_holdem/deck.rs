@@ -1,24 +1,106 @@
 use rand::Rng;
 
 use super::*;
-use std::fmt;
+use std::{fmt, ops::RangeInclusive};
 
+#[derive(Clone)]
 pub struct Deck {
     pos: usize,
     cards: Vec<Card>,
 }
 
-pub fn all_cards() -> Vec<Card> {
-    let mut cards = Vec::with_capacity(13 * 4);
-    for suit in &SUITS {
-        for rank in 2..15 {
-            cards.push(Card {
-                rank,
-                suit: *suit,
-            });
+/// Describes the composition of a [`Deck`]: the rank range and suit set that
+/// make up the main grid, plus how many jokers to append. The default is the
+/// standard 52-card deck (ranks `2..=14` across all four suits, no jokers), so
+/// existing call sites keep the same deck they had before.
+///
+/// Parameterizing the deck this way lets the same shuffling/drawing machinery
+/// serve short-deck hold'em, joker games, or other rank/suit ranges without
+/// duplicating the deck code.
+#[derive(Debug, Clone)]
+pub struct DeckSpec {
+    pub ranks: RangeInclusive<Rank>,
+    pub suits: Vec<Suit>,
+    pub jokers: usize,
+}
+
+impl Default for DeckSpec {
+    fn default() -> DeckSpec {
+        DeckSpec {
+            ranks: RANKS,
+            suits: SUITS.to_vec(),
+            jokers: 0,
+        }
+    }
+}
+
+impl DeckSpec {
+    /// Builds a spec from an explicit rank range and suit set, with no jokers.
+    /// Combine with [`DeckSpec::with_jokers`] to add them.
+    pub fn new(ranks: RangeInclusive<Rank>, suits: Vec<Suit>) -> DeckSpec {
+        DeckSpec {
+            ranks,
+            suits,
+            jokers: 0,
+        }
+    }
+
+    /// Short-deck ("six-plus") hold'em: ranks `6..=14` across all four suits.
+    pub fn short_deck() -> DeckSpec {
+        DeckSpec {
+            ranks: 6..=14,
+            ..DeckSpec::default()
+        }
+    }
+
+    /// A reduced deck, such as the tiny Leduc universe (two suits, three ranks).
+    /// This lets games declare their own card universe without touching
+    /// `card.rs`.
+    pub fn reduced(ranks: RangeInclusive<Rank>, suits: &[Suit]) -> DeckSpec {
+        DeckSpec::new(ranks, suits.to_vec())
+    }
+
+    /// Returns the spec with `jokers` jokers appended, mirroring the common
+    /// "with or without jokers" deck builder.
+    pub fn with_jokers(mut self, jokers: usize) -> DeckSpec {
+        self.jokers = jokers;
+        self
+    }
+
+    /// The number of cards this spec materializes, jokers included.
+    pub fn count(&self) -> usize {
+        self.ranks.clone().count() * self.suits.len() + self.jokers
+    }
+
+    /// Materializes the deck and shuffles the first `n` cards ready to be drawn.
+    pub fn shuffled<T: Rng>(&self, rng: &mut T, n: usize) -> Deck {
+        let mut deck = Deck::from_spec(self);
+        deck.shuffle_first_n(rng, n);
+        deck
+    }
+
+    /// Materializes the ordered card list this spec describes. Suits vary in the
+    /// outer loop and ranks in the inner loop, matching the historical
+    /// [`all_cards`] ordering; jokers are appended last with the sentinel
+    /// [`JOKER_RANK`], cycling through the suit set, so they are distinct cards
+    /// only while `jokers <= suits.len()`.
+    pub fn cards(&self) -> Vec<Card> {
+        let mut cards =
+            Vec::with_capacity(self.ranks.clone().count() * self.suits.len() + self.jokers);
+        for suit in &self.suits {
+            for rank in self.ranks.clone() {
+                cards.push(Card::new(rank, *suit));
+            }
         }
+        for i in 0..self.jokers {
+            cards.push(Card::new(JOKER_RANK, self.suits[i % self.suits.len()]));
+        }
+        cards
     }
-    cards
+}
+
+pub fn all_cards() -> Vec<Card> {
+    DeckSpec::default().cards()
 }
 
 impl Default for Deck {
@@ -32,6 +114,15 @@ impl Default for Deck {
 }
 
 impl Deck {
+    /// Builds a deck from an explicit [`DeckSpec`]. `Deck::default()` is
+    /// equivalent to `Deck::from_spec(&DeckSpec::default())`.
+    pub fn from_spec(spec: &DeckSpec) -> Deck {
+        Deck {
+            pos: 0,
+            cards: spec.cards(),
+        }
+    }
+
     pub fn empty() -> Deck {
         Deck {
             pos: 0,
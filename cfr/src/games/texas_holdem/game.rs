@@ -15,6 +15,20 @@ pub struct TexasHoldemGame<S: RootNodeSampler> {
 
 pub type SubTreeId = usize;
 
+/// `C(n, k)`, computed iteratively so the uniform chance probability can be
+/// found without enumerating the combinations.
+pub fn binomial(n: usize, k: usize) -> usize {
+    if k > n {
+        return 0;
+    }
+    let k = k.min(n - k);
+    let mut result = 1usize;
+    for i in 0..k {
+        result = result * (n - i) / (i + 1);
+    }
+    result
+}
+
 #[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum NodeType {
     /// a.k.a. Root node for the entire game tree
@@ -41,6 +55,76 @@ pub enum NodeType {
     PlayerNode,
 }
 
+/// Rolling hash of the public information (the betting line and the board).
+///
+/// It is updated incrementally in O(1) inside `with_action` — rather than
+/// rebuilt from the full history on every `to_info_set` — so CFR table lookups
+/// avoid cloning the action/community vectors. Private hole cards are never
+/// folded in here.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct PublicState {
+    hash: u64,
+}
+
+impl PublicState {
+    const PRIME: u64 = 0x100000001b3; // FNV-1a prime
+
+    fn fold(&mut self, key: u64) {
+        self.hash = (self.hash ^ key).wrapping_mul(Self::PRIME);
+    }
+
+    fn fold_card(&mut self, card: &Card) {
+        self.fold(Cards::card_index(card) as u64 + 1);
+    }
+
+    /// Folds the public effect of `action` into the rolling hash. Private deals
+    /// (`DealHands`) and sub-tree moves contribute nothing to common knowledge.
+    pub fn fold_action(&mut self, action: &TexasHoldemAction) {
+        match action {
+            TexasHoldemAction::DealHands(_, _) | TexasHoldemAction::MoveToSubTreeRoot(_) => {}
+            TexasHoldemAction::OpenFlop(cards) => cards.iter().for_each(|c| self.fold_card(c)),
+            TexasHoldemAction::OpenTurn(card) | TexasHoldemAction::OpenRiver(card) => {
+                self.fold_card(card)
+            }
+            TexasHoldemAction::HandleAllInAtPreFlop(cards) => {
+                cards.iter().for_each(|c| self.fold_card(c))
+            }
+            TexasHoldemAction::HandleAllInAtFlop(cards) => {
+                cards.iter().for_each(|c| self.fold_card(c))
+            }
+            TexasHoldemAction::HandleAllInAtTurn(cards) => {
+                cards.iter().for_each(|c| self.fold_card(c))
+            }
+            TexasHoldemAction::HandleAllInAtRiver() => self.fold(0xa11_1),
+            TexasHoldemAction::PlayerAction(act) => match act {
+                Action::Fold => self.fold(0x01),
+                Action::Call => self.fold(0x02),
+                Action::RaiseTo(amount) => self.fold(0x03 ^ (*amount as u64) << 8),
+            },
+        }
+    }
+
+    /// Rebuilds the rolling hash by folding a full public-action history from
+    /// scratch. Used only by the debug-only assertion path in `to_info_set` to
+    /// check that the incremental updates have stayed in sync.
+    pub fn from_history(history: &[TexasHoldemAction]) -> Self {
+        let mut public = PublicState::default();
+        for action in history {
+            public.fold_action(action);
+        }
+        public
+    }
+
+    /// Whether `action` is visible to both players and therefore part of the
+    /// public hash (as opposed to a private deal or an internal sub-tree move).
+    fn is_public(action: &TexasHoldemAction) -> bool {
+        !matches!(
+            action,
+            TexasHoldemAction::DealHands(_, _) | TexasHoldemAction::MoveToSubTreeRoot(_)
+        )
+    }
+}
+
 /// An enum which represents a game tree node.
 /// Note that the tree represents only a single hand (i.e. it cannot be used to represent a single table tournament)
 #[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
@@ -51,6 +135,12 @@ pub struct TexasHoldemNode {
     // (e.g. hole cards)
     pub player_action_history: Vec<TexasHoldemAction>,
     pub hand_state: HandState,
+    /// Rolling hash of the public information, updated in O(1) by `with_action`.
+    pub public: PublicState,
+    /// Full public-action history, kept only in debug builds so `to_info_set`
+    /// can assert the incremental `public` hash matches a from-scratch rebuild.
+    #[cfg(debug_assertions)]
+    public_history: Vec<TexasHoldemAction>,
 }
 
 impl TexasHoldemNode {
@@ -59,6 +149,9 @@ impl TexasHoldemNode {
             node_type: NodeType::DealHands,
             player_action_history: vec![],
             hand_state: HandState::default(),
+            public: PublicState::default(),
+            #[cfg(debug_assertions)]
+            public_history: vec![],
         }
     }
 
@@ -67,6 +160,9 @@ impl TexasHoldemNode {
             node_type: NodeType::SubTreeRoot,
             player_action_history: vec![],
             hand_state: HandState::default(),
+            public: PublicState::default(),
+            #[cfg(debug_assertions)]
+            public_history: vec![],
         }
     }
 }
@@ -92,8 +188,33 @@ pub enum TexasHoldemAction {
 }
 
 impl fmt::Display for TexasHoldemAction {
-    fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        todo!()
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TexasHoldemAction::DealHands(player_id, cards) => {
+                write!(f, "deal p{}: {} {}", player_id.index(), cards[0], cards[1])
+            }
+            TexasHoldemAction::MoveToSubTreeRoot(id) => write!(f, "sub_tree {}", id),
+            TexasHoldemAction::OpenFlop(cards) => {
+                write!(f, "flop: {} {} {}", cards[0], cards[1], cards[2])
+            }
+            TexasHoldemAction::OpenTurn(card) => write!(f, "turn: {}", card),
+            TexasHoldemAction::OpenRiver(card) => write!(f, "river: {}", card),
+            TexasHoldemAction::HandleAllInAtPreFlop(cards) => {
+                write!(f, "run out: {}", cards_to_str(cards))
+            }
+            TexasHoldemAction::HandleAllInAtFlop(cards) => {
+                write!(f, "run out: {}", cards_to_str(cards))
+            }
+            TexasHoldemAction::HandleAllInAtTurn(cards) => {
+                write!(f, "run out: {}", cards_to_str(cards))
+            }
+            TexasHoldemAction::HandleAllInAtRiver() => write!(f, "run out"),
+            TexasHoldemAction::PlayerAction(act) => match act {
+                Action::Fold => write!(f, "fold"),
+                Action::Call => write!(f, "call"),
+                Action::RaiseTo(amount) => write!(f, "raise to {}", amount),
+            },
+        }
     }
 }
 
@@ -106,9 +227,12 @@ pub trait RootNodeSampler {
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct TexasHoldemInfoSet {
+    /// The acting player's private hole cards.
     hole_cards: Vec<Card>,
-    community_cards: Vec<Card>,
-    player_actions: Vec<TexasHoldemAction>,
+    /// Precomputed rolling hash of everything public (the board and the betting
+    /// line). Combined with `hole_cards` it uniquely keys an infoset without
+    /// re-cloning the community cards and action history on every lookup.
+    public_hash: u64,
 }
 
 impl fmt::Display for TexasHoldemInfoSet {
@@ -132,12 +256,19 @@ impl<S: RootNodeSampler> Game for TexasHoldemGame<S> {
     }
 
     fn to_info_set(&self, state: &Self::State) -> Self::InfoSet {
-        // TODO: No need of copying the actions vector?
-        //   it might be better to calculate a hash here and just store the hash value here?
+        // The public board and betting line are folded incrementally into
+        // `state.public` as the hand is played, so the lookup key only has to
+        // clone the acting player's hole cards here.
+        #[cfg(debug_assertions)]
+        debug_assert_eq!(
+            state.public,
+            PublicState::from_history(&state.public_history),
+            "incremental public hash drifted from the rebuilt one: {:?}",
+            state.public_history
+        );
         TexasHoldemInfoSet {
             hole_cards: state.hand_state.players[state.hand_state.next_player].hole_cards.clone(),
-            community_cards: state.hand_state.community_cards.clone(),
-            player_actions: state.player_action_history.clone(),
+            public_hash: state.public.hash,
         }
     }
 
@@ -145,12 +276,12 @@ impl<S: RootNodeSampler> Game for TexasHoldemGame<S> {
         state.node_type == NodeType::TerminalNode
     }
 
-    fn get_payouts(&self, state: &Self::State) -> [f64; 2] {
+    fn get_payouts(&self, state: &Self::State) -> Vec<f64> {
         let result = self.dealer.calculate_won_pots(&state.hand_state);
         assert_eq!(2, result.won_pots.len());
         // Normalize payouts by big blind
         let big_blind: f64 = *self.dealer.get_rule().blinds.iter().max().unwrap() as f64;
-        [result.won_pots[0] as f64 / big_blind, result.won_pots[1] as f64 / big_blind]
+        result.won_pots.iter().map(|&won| won as f64 / big_blind).collect()
     }
 
     fn get_node_player_id(&self, state: &Self::State) -> crate::games::PlayerId {
@@ -165,8 +296,37 @@ impl<S: RootNodeSampler> Game for TexasHoldemGame<S> {
         // Action history which is visible from BOTH players.
         let mut new_history = state.player_action_history.clone();
 
+        // Fold the public effect of this action into the rolling hash in O(1).
+        let mut public = state.public;
+        public.fold_action(&action);
+        #[cfg(debug_assertions)]
+        let public_history = {
+            let mut h = state.public_history.clone();
+            if PublicState::is_public(&action) {
+                h.push(action);
+            }
+            h
+        };
+
         let (node_type, next_hand_state) = match action {
-            TexasHoldemAction::DealHands(_, _) => todo!(),
+            TexasHoldemAction::DealHands(player_id, cards) => {
+                let mut next_hand_state = state.hand_state.clone();
+                // The very first deal initializes the preflop round so the
+                // seats, stacks and blinds exist before hole cards are handed out.
+                if next_hand_state.players.is_empty() {
+                    self.dealer.init_round(&mut next_hand_state, Round::Preflop);
+                }
+                next_hand_state.players[player_id.index()].hole_cards = cards.to_vec();
+                let node_type = match next_hand_state.next_undealt_player() {
+                    Some(_) => NodeType::DealHands,
+                    // Everyone has cards: re-seat to the preflop first player.
+                    None => {
+                        self.dealer.init_round(&mut next_hand_state, Round::Preflop);
+                        NodeType::PlayerNode
+                    }
+                };
+                (node_type, next_hand_state)
+            }
             TexasHoldemAction::MoveToSubTreeRoot(sub_tree_id) => {
                 let root_state = self
                     .root_node_sampler
@@ -214,7 +374,18 @@ impl<S: RootNodeSampler> Game for TexasHoldemGame<S> {
                 );
                 (NodeType::PlayerNode, next_hand_state)
             }
-            TexasHoldemAction::HandleAllInAtPreFlop(_) => todo!(),
+            TexasHoldemAction::HandleAllInAtPreFlop(cards) => {
+                let mut next_hand_state = state.hand_state.clone();
+                next_hand_state.community_cards.extend_from_slice(&cards);
+                assert_eq!(
+                    5,
+                    next_hand_state.community_cards.len(),
+                    "state: {:?}, action: {:?}",
+                    state,
+                    action
+                );
+                (NodeType::TerminalNode, next_hand_state)
+            }
             TexasHoldemAction::HandleAllInAtFlop(cards) => {
                 let mut next_hand_state = state.hand_state.clone();
                 next_hand_state.community_cards.extend_from_slice(&cards);
@@ -252,7 +423,11 @@ impl<S: RootNodeSampler> Game for TexasHoldemGame<S> {
                 let node_type = match update_result {
                     UpdateResult::Keep => NodeType::PlayerNode,
                     UpdateResult::NextRound(next_round) => match next_round {
-                        Round::Preflop => todo!(),
+                        // Betting always advances forward, so `Round::next`
+                        // never yields Preflop here.
+                        Round::Preflop => {
+                            unreachable!("a finished betting round never rewinds to Preflop")
+                        }
                         Round::Flop => NodeType::OpenFlop,
                         Round::Turn => NodeType::OpenTurn,
                         Round::River => NodeType::OpenRiver,
@@ -268,6 +443,9 @@ impl<S: RootNodeSampler> Game for TexasHoldemGame<S> {
             node_type,
             player_action_history: new_history,
             hand_state: next_hand_state,
+            public,
+            #[cfg(debug_assertions)]
+            public_history,
         }
     }
 
@@ -286,76 +464,24 @@ impl<S: RootNodeSampler> Game for TexasHoldemGame<S> {
         }
     }
 
+    /// Thin `Vec`-returning wrapper over [`Self::list_legal_chance_actions_iter`].
+    /// Prefer the iterator form in the CFR hot path to avoid materializing the
+    /// whole (potentially ~1.7M entry) combination list.
     fn list_legal_chance_actions(&self, state: &Self::State) -> Vec<(Self::Action, f64)> {
-        let mut acts = vec![];
-        match state.node_type {
-            NodeType::DealHands => todo!(),
-            NodeType::SubTreeRoot => {
-                let probs =
-                    self.root_node_sampler.as_ref().unwrap().get_sub_tree_reach_probabilities();
-                for (id, prob) in probs.iter().enumerate() {
-                    acts.push((TexasHoldemAction::MoveToSubTreeRoot(id), *prob));
-                }
-            }
-            NodeType::OpenFlop => {
-                let available_cards = state.hand_state.get_available_cards().to_vec();
-                let comb = available_cards.into_iter().combinations(3).collect_vec();
-                let unif_prob = 1.0 / comb.len() as f64;
-                for opened in &comb {
-                    let act = TexasHoldemAction::OpenFlop([opened[0], opened[1], opened[2]]);
-                    acts.push((act, unif_prob));
-                }
-            }
-            NodeType::OpenTurn => {
-                let available_cards = state.hand_state.get_available_cards().to_vec();
-                let unif_prob = 1.0 / available_cards.len() as f64;
-                for card in available_cards.into_iter() {
-                    let act = TexasHoldemAction::OpenTurn(card);
-                    acts.push((act, unif_prob));
-                }
-            }
-            NodeType::OpenRiver => {
-                let available_cards = state.hand_state.get_available_cards().to_vec();
-                let unif_prob = 1.0 / available_cards.len() as f64;
-                for card in available_cards.into_iter() {
-                    let act = TexasHoldemAction::OpenRiver(card);
-                    acts.push((act, unif_prob));
-                }
-            }
-            NodeType::EveryoneAllIn => {
-                let comm_len = state.hand_state.community_cards.len();
-                let deal_cnt = 5 - comm_len;
-                if deal_cnt == 0 {
-                    // Players are on river
-                    panic!("EveryoneAllIn node shouldn't be used if everyone all-in in river. Use terminal node instead.");
-                }
-
-                let available_cards = state.hand_state.get_available_cards().to_vec();
-                let comb = available_cards.into_iter().combinations(deal_cnt).collect_vec();
-                let unif_prob = 1.0 / comb.len() as f64;
-                for opened in &comb {
-                    let act = match deal_cnt {
-                        5 => TexasHoldemAction::HandleAllInAtPreFlop([
-                            opened[0], opened[1], opened[2], opened[3], opened[4],
-                        ]),
-                        2 => TexasHoldemAction::HandleAllInAtFlop([opened[0], opened[1]]),
-                        1 => TexasHoldemAction::HandleAllInAtTurn([opened[0]]),
-                        _ => panic!("Unknown deal_cnt: {} when we list all chance actions for EveryoneAllIn", deal_cnt),
-                    };
-                    acts.push((act, unif_prob));
-                }
-            }
-            NodeType::TerminalNode => todo!(),
-            NodeType::PlayerNode => {
-                panic!("list_legal_chance_actions is called on a player node: {:?}", state);
-            }
-        }
-        acts
+        self.list_legal_chance_actions_iter(state).collect()
     }
 
     fn sample_chance_action<R: Rng>(&self, rng: &mut R, state: &Self::State) -> Self::Action {
         match state.node_type {
-            NodeType::DealHands => todo!(),
+            NodeType::DealHands => {
+                let seated = self.seated_hand_state(state);
+                let player_id = PlayerId::Player(seated.next_undealt_player().unwrap());
+                let mut available_cards = seated.get_available_cards();
+                TexasHoldemAction::DealHands(
+                    player_id,
+                    [available_cards.sample_card(rng), available_cards.sample_card(rng)],
+                )
+            }
             NodeType::SubTreeRoot => {
                 let sub_tree_id = self.root_node_sampler.as_ref().unwrap().sample_sub_tree_id(rng);
                 TexasHoldemAction::MoveToSubTreeRoot(sub_tree_id)
@@ -428,6 +554,150 @@ impl<S: RootNodeSampler> TexasHoldemGame<S> {
         }
     }
 
+    /// Lazily enumerates the legal chance actions at `state`.
+    ///
+    /// The uniform probability is computed from the combination count with
+    /// [`binomial`] (an O(1) formula) rather than by materializing and counting
+    /// the combinations, and the combinations themselves are produced on demand
+    /// by `itertools::combinations`, so the caller pays only for the entries it
+    /// consumes.
+    pub fn list_legal_chance_actions_iter<'a>(
+        &'a self,
+        state: &'a TexasHoldemNode,
+    ) -> Box<dyn Iterator<Item = (TexasHoldemAction, f64)> + 'a> {
+        match state.node_type {
+            NodeType::DealHands => {
+                let seated = self.seated_hand_state(state);
+                let player_id = PlayerId::Player(seated.next_undealt_player().unwrap());
+                let available = seated.get_available_cards();
+                let prob = 1.0 / binomial(available.len(), 2) as f64;
+                Box::new(available.into_iter().combinations(2).map(move |hole| {
+                    (TexasHoldemAction::DealHands(player_id, [hole[0], hole[1]]), prob)
+                }))
+            }
+            NodeType::SubTreeRoot => {
+                let probs =
+                    self.root_node_sampler.as_ref().unwrap().get_sub_tree_reach_probabilities();
+                Box::new(
+                    probs
+                        .iter()
+                        .enumerate()
+                        .map(|(id, prob)| (TexasHoldemAction::MoveToSubTreeRoot(id), *prob)),
+                )
+            }
+            NodeType::OpenFlop => {
+                let available = state.hand_state.get_available_cards();
+                let prob = 1.0 / binomial(available.len(), 3) as f64;
+                Box::new(available.into_iter().combinations(3).map(move |opened| {
+                    (TexasHoldemAction::OpenFlop([opened[0], opened[1], opened[2]]), prob)
+                }))
+            }
+            NodeType::OpenTurn => {
+                let available = state.hand_state.get_available_cards();
+                let prob = 1.0 / available.len() as f64;
+                Box::new(
+                    available
+                        .into_iter()
+                        .map(move |card| (TexasHoldemAction::OpenTurn(card), prob)),
+                )
+            }
+            NodeType::OpenRiver => {
+                let available = state.hand_state.get_available_cards();
+                let prob = 1.0 / available.len() as f64;
+                Box::new(
+                    available
+                        .into_iter()
+                        .map(move |card| (TexasHoldemAction::OpenRiver(card), prob)),
+                )
+            }
+            NodeType::EveryoneAllIn => {
+                let deal_cnt = 5 - state.hand_state.community_cards.len();
+                if deal_cnt == 0 {
+                    panic!("EveryoneAllIn node shouldn't be used if everyone all-in in river. Use terminal node instead.");
+                }
+                let available = state.hand_state.get_available_cards();
+                let prob = 1.0 / binomial(available.len(), deal_cnt) as f64;
+                Box::new(available.into_iter().combinations(deal_cnt).map(move |opened| {
+                    let act = match deal_cnt {
+                        5 => TexasHoldemAction::HandleAllInAtPreFlop([
+                            opened[0], opened[1], opened[2], opened[3], opened[4],
+                        ]),
+                        2 => TexasHoldemAction::HandleAllInAtFlop([opened[0], opened[1]]),
+                        1 => TexasHoldemAction::HandleAllInAtTurn([opened[0]]),
+                        _ => panic!("Unknown deal_cnt: {} when we list all chance actions for EveryoneAllIn", deal_cnt),
+                    };
+                    (act, prob)
+                }))
+            }
+            NodeType::TerminalNode => todo!(),
+            NodeType::PlayerNode => {
+                panic!("list_legal_chance_actions is called on a player node: {:?}", state);
+            }
+        }
+    }
+
+    /// Returns a copy of `state`'s hand state guaranteed to have its seats
+    /// materialized, so the `DealHands` chance node can reason about which seat
+    /// is next and which cards are still live even at the very root.
+    fn seated_hand_state(&self, state: &TexasHoldemNode) -> HandState {
+        let mut hand_state = state.hand_state.clone();
+        if hand_state.players.is_empty() {
+            self.dealer.init_round(&mut hand_state, Round::Preflop);
+        }
+        hand_state
+    }
+
+    /// Serializes a completed hand into a structured JSON record: the dealt
+    /// hole cards per seat, the community cards, the visible action history
+    /// (rendered in standard poker notation) and the resolved payouts. The
+    /// schema is stable so it can be fed into an external hand viewer or diffed
+    /// across solver runs.
+    pub fn export_hand(&self, state: &TexasHoldemNode) -> serde_json::Value {
+        let hole_cards: Vec<String> = state
+            .hand_state
+            .players
+            .iter()
+            .map(|p| cards_to_str(&p.hole_cards))
+            .collect();
+        let community_cards = cards_to_str(&state.hand_state.community_cards);
+        let actions: Vec<String> =
+            state.player_action_history.iter().map(|a| format!("{}", a)).collect();
+        let payouts = if self.is_terminal(state) {
+            Some(self.get_payouts(state))
+        } else {
+            None
+        };
+        serde_json::json!({
+            "hole_cards": hole_cards,
+            "community_cards": community_cards,
+            "actions": actions,
+            "payouts": payouts,
+        })
+    }
+
+    /// Plays a full hand from `new_root()` to a terminal node, sampling chance
+    /// actions with `rng` and asking `policy` for each player action. Because
+    /// every chance outcome is drawn from `rng`, seeding it (e.g. with
+    /// `ChaChaRng::from_seed`) makes the entire hand — hole cards, board and
+    /// actions — reproducible from a single seed.
+    pub fn play_hand<R, P>(&self, rng: &mut R, mut policy: P) -> HandResult
+    where
+        R: Rng,
+        P: FnMut(&TexasHoldemNode, &[TexasHoldemAction]) -> TexasHoldemAction,
+    {
+        let mut state = self.new_root();
+        while !self.is_terminal(&state) {
+            let action = if self.get_node_player_id(&state) == PlayerId::Chance {
+                self.sample_chance_action(rng, &state)
+            } else {
+                let actions = self.list_legal_actions(&state);
+                policy(&state, &actions)
+            };
+            state = self.with_action(&state, action);
+        }
+        self.dealer.calculate_won_pots(&state.hand_state)
+    }
+
     /*
         fn apply_player_action(
             &self,
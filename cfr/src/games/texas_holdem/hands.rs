@@ -71,7 +71,8 @@ fn hand_type_name(t: HandType) -> String {
     .to_string()
 }
 
-#[derive(Copy, Clone, PartialOrd, PartialEq, Ord, Eq)]
+#[derive(Copy, Clone, Hash, PartialOrd, PartialEq, Ord, Eq)]
+#[derive(serde::Serialize, serde::Deserialize)]
 pub struct HandScore {
     pub value: u32,
 }
@@ -119,7 +120,7 @@ impl HandScore {
         let mut sht = RANK_SIZE_BITS * 5;
         for card in cards.iter().take(5) {
             sht -= RANK_SIZE_BITS;
-            value |= u32::from(card.rank) << sht;
+            value |= u32::from(card.rank()) << sht;
         }
         HandScore {
             value,
@@ -153,16 +154,16 @@ fn make_round_by_rank(sorted_by_rank: &[Card]) -> Vec<Card> {
     if sorted_by_rank.is_empty() {
         return vec![];
     }
-    if sorted_by_rank[0].rank != 14 || sorted_by_rank.last().unwrap().rank != 2 {
+    if sorted_by_rank[0].rank() != 14 || sorted_by_rank.last().unwrap().rank() != 2 {
         return sorted_by_rank.to_vec();
     }
-    let aces = sorted_by_rank.iter().filter(|c| c.rank == 14);
+    let aces = sorted_by_rank.iter().filter(|c| c.rank() == 14);
     sorted_by_rank.iter().chain(aces).cloned().collect()
 }
 
 fn sort_cards_by_rank(cards: &[Card]) -> Vec<Card> {
     let mut sorted_by_rank = cards.to_vec();
-    sorted_by_rank.sort_by(|a, b| b.rank.cmp(&a.rank));
+    sorted_by_rank.sort_by(|a, b| b.rank().cmp(&a.rank()));
     sorted_by_rank
 }
 
@@ -263,12 +264,12 @@ pub fn is_straight(rounded: &[Card]) -> Option<HandScore> {
     let mut connected = 0;
     let mut connected_cards = [Card::dummy(); 5];
     for &card in rounded {
-        if card.rank == cur {
+        if card.rank() == cur {
             // same rank.
             continue;
         }
 
-        if card.rank != next {
+        if card.rank() != next {
             connected = 0;
         }
         connected_cards[connected] = card;
@@ -277,11 +278,11 @@ pub fn is_straight(rounded: &[Card]) -> Option<HandScore> {
             return Some(HandScore::new(STRAIGHT, &connected_cards));
         }
 
-        cur = card.rank;
-        next = if card.rank == 2 {
+        cur = card.rank();
+        next = if card.rank() == 2 {
             14
         } else {
-            card.rank - 1
+            card.rank() - 1
         };
     }
     None
@@ -355,7 +356,7 @@ fn group_by_rank(by_rank: &[Card]) -> Vec<Vec<Card>> {
     let mut groups = vec![];
     let mut g = vec![card];
     for card in it {
-        if g[0].rank == card.rank {
+        if g[0].rank() == card.rank() {
             g.push(*card);
         } else {
             groups.push(g);
@@ -378,7 +379,7 @@ fn group_by_suit(by_rank: &[Card]) -> Vec<Vec<Card>> {
     }
     let mut groups: Vec<Vec<Card>> = vec![vec![], vec![], vec![], vec![]];
     for card in by_rank {
-        let index = card.suit as usize;
+        let index = card.suit() as usize;
         groups[index].push(*card);
     }
     groups.sort_by_key(|b| Reverse(b.len()));
@@ -403,22 +404,22 @@ mod tests {
         let groups = group_by_rank(&sorted_by_rank);
         // 3 Kings
         assert_eq!(3, groups[0].len());
-        assert_eq!(13, groups[0][0].rank);
-        assert_eq!(13, groups[0][1].rank);
-        assert_eq!(13, groups[0][2].rank);
+        assert_eq!(13, groups[0][0].rank());
+        assert_eq!(13, groups[0][1].rank());
+        assert_eq!(13, groups[0][2].rank());
 
         // 2 8s
         assert_eq!(2, groups[1].len());
-        assert_eq!(8, groups[1][0].rank);
-        assert_eq!(8, groups[1][1].rank);
+        assert_eq!(8, groups[1][0].rank());
+        assert_eq!(8, groups[1][1].rank());
 
         // 1 Ace
         assert_eq!(1, groups[2].len());
-        assert_eq!(14, groups[2][0].rank);
+        assert_eq!(14, groups[2][0].rank());
 
         // 10
         assert_eq!(1, groups[3].len());
-        assert_eq!(10, groups[3][0].rank);
+        assert_eq!(10, groups[3][0].rank());
     }
 
     #[test]
@@ -428,22 +429,22 @@ mod tests {
         let groups = group_by_rank(&sorted_by_rank);
         // T * 2
         assert_eq!(2, groups[0].len());
-        assert_eq!(10, groups[0][0].rank);
-        assert_eq!(10, groups[0][1].rank);
+        assert_eq!(10, groups[0][0].rank());
+        assert_eq!(10, groups[0][1].rank());
 
         // 8 * 2
         assert_eq!(2, groups[1].len());
-        assert_eq!(8, groups[1][0].rank);
-        assert_eq!(8, groups[1][1].rank);
+        assert_eq!(8, groups[1][0].rank());
+        assert_eq!(8, groups[1][1].rank());
 
         // 3 * 2
         assert_eq!(2, groups[2].len());
-        assert_eq!(3, groups[2][0].rank);
-        assert_eq!(3, groups[2][1].rank);
+        assert_eq!(3, groups[2][0].rank());
+        assert_eq!(3, groups[2][1].rank());
 
         // A
         assert_eq!(1, groups[3].len());
-        assert_eq!(14, groups[3][0].rank);
+        assert_eq!(14, groups[3][0].rank());
     }
 
     #[test]
@@ -464,8 +465,8 @@ mod tests {
 
         let r = make_round_by_rank(&parse_cards("Ah Kh Jh Th 8h 7h 2h"));
         assert_eq!(8, r.len());
-        assert_eq!(14, r[0].rank);
-        assert_eq!(14, r[7].rank);
+        assert_eq!(14, r[0].rank());
+        assert_eq!(14, r[7].rank());
 
         // We don't need to round the cards if the last one is not 2.
         let r = make_round_by_rank(&parse_cards("Ah Kh Jh Th 8h 7h 4h"));
@@ -0,0 +1,128 @@
+//! A stable JSON hand-history format for replaying solved hands.
+//!
+//! A [`HandHistory`] captures everything needed to reconstruct a single
+//! played-out hand outside the Rust process: the deck seed, the hole cards
+//! dealt to each seat, the community cards, the ordered list of actions (each
+//! tagged with the [`Round`] it was taken in) and the resolved [`HandResult`].
+//! The schema is one record per hand, mirroring the replay logs fed into web
+//! hand viewers, so strategies can be diffed across training checkpoints and
+//! payout/side-pot computations debugged without re-running the solver.
+
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use super::*;
+
+/// A single action taken during a hand, tagged with the round it occurred in.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActionRecord {
+    pub round: String,
+    pub action: String,
+}
+
+/// The resolved outcome of a hand, serialized per seat.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResultRecord {
+    pub won_pots: Vec<i32>,
+    /// Raw packed [`HandScore`] values, paired with their human-readable forms.
+    pub hand_values: Vec<u32>,
+    pub hands: Vec<String>,
+}
+
+/// A full hand trajectory in a stable, self-contained JSON schema.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HandHistory {
+    pub seed: u64,
+    /// Hole cards per seat, rendered like `"AsKd"`.
+    pub hole_cards: Vec<String>,
+    /// The board, rendered like `"2h7c9s"`.
+    pub community_cards: String,
+    pub actions: Vec<ActionRecord>,
+    pub result: ResultRecord,
+}
+
+impl HandHistory {
+    /// Builds a history from the terminal [`HandState`], the seed that produced
+    /// the deal and the ordered actions taken (each with the round it was taken
+    /// in).
+    pub fn from_hand(
+        seed: u64,
+        state: &HandState,
+        actions: &[(Round, Action)],
+        result: &HandResult,
+    ) -> HandHistory {
+        let hole_cards =
+            state.players.iter().map(|p| cards_to_str(&p.hole_cards)).collect();
+        let actions = actions
+            .iter()
+            .map(|(round, action)| ActionRecord {
+                round: round.to_string(),
+                action: action.to_string(),
+            })
+            .collect();
+        let result = ResultRecord {
+            won_pots: result.won_pots.clone(),
+            hand_values: result.hands.iter().map(|h| h.value).collect(),
+            hands: result.hands.iter().map(|h| h.to_string()).collect(),
+        };
+        HandHistory {
+            seed,
+            hole_cards,
+            community_cards: cards_to_str(&state.community_cards),
+            actions,
+            result,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+
+    pub fn from_json(s: &str) -> serde_json::Result<HandHistory> {
+        serde_json::from_str(s)
+    }
+
+    /// Reconstructs the per-seat hole cards as parsed [`Card`]s.
+    pub fn parse_hole_cards(&self) -> Vec<Vec<Card>> {
+        self.hole_cards.iter().map(|s| parse_cards(s)).collect()
+    }
+
+    pub fn parse_community_cards(&self) -> Vec<Card> {
+        parse_cards(&self.community_cards)
+    }
+
+    /// Reconstructs the ordered `(round, action)` trajectory.
+    pub fn parse_actions(&self) -> Vec<(Round, Action)> {
+        self.actions
+            .iter()
+            .map(|rec| (parse_round(&rec.round), parse_action(&rec.action)))
+            .collect()
+    }
+}
+
+fn parse_round(s: &str) -> Round {
+    match s {
+        "Preflop" => Round::Preflop,
+        "Flop" => Round::Flop,
+        "Turn" => Round::Turn,
+        "River" => Round::River,
+        _ => panic!("Unknown round: {}", s),
+    }
+}
+
+fn parse_action(s: &str) -> Action {
+    match s {
+        "Fold" => Action::Fold,
+        "Call" => Action::Call,
+        other => {
+            let amount = other
+                .strip_prefix("RaiseTo(")
+                .and_then(|rest| rest.strip_suffix(')'))
+                .and_then(|n| n.parse().ok())
+                .unwrap_or_else(|| panic!("Unknown action: {}", s));
+            Action::RaiseTo(amount)
+        }
+    }
+}
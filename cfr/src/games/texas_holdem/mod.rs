@@ -1,21 +1,29 @@
 pub mod abstraction;
+pub mod acpc;
 pub mod card;
+pub mod cards;
 pub mod dealer;
 pub mod deck;
 pub mod game;
 pub mod hands;
+pub mod json;
 pub mod post_flop;
+pub mod range;
 pub mod rule;
 pub mod states;
 
 pub use self::{
     abstraction::*,
+    acpc::*,
     card::*,
+    cards::*,
     dealer::*,
     deck::*,
     game::*,
     hands::*,
+    json::*,
     post_flop::*,
+    range::*,
     rule::*,
     states::*,
 };
@@ -103,7 +103,7 @@ impl PreflopStrategy {
     }
 
     pub fn get(&self, card0: &Card, card1: &Card) -> f64 {
-        self.get_from_ranks(card0.rank, card1.rank, card0.suit == card1.suit)
+        self.get_from_ranks(card0.rank(), card1.rank(), card0.suit() == card1.suit())
     }
 
     pub fn get_from_slice(&self, cards: &[Card]) -> f64 {
@@ -127,7 +127,7 @@ impl PreflopStrategy {
     }
 
     pub fn set(&mut self, card0: &Card, card1: &Card, prob: f64) {
-        let (y, x) = self.to_indices(card0.rank, card1.rank, card0.suit == card1.suit);
+        let (y, x) = self.to_indices(card0.rank(), card1.rank(), card0.suit() == card1.suit());
         self.strategy[y][x] = prob;
     }
 }
@@ -209,7 +209,7 @@ impl RootNodeSampler for TexasHoldemPostFlopNodeSampler {
 
         // Set hole cards for each players
         hand_state.players[self.player_id.index()].hole_cards = self.player_hand.to_vec();
-        hand_state.players[self.player_id.opponent().index()].hole_cards =
+        hand_state.players[self.player_id.next_player(2).index()].hole_cards =
             self.opponent_hand_probabilities[id].0.to_vec();
 
         // Set community cards
@@ -363,7 +363,7 @@ mod tests {
         let aa_probs: f64 = probs
             .iter()
             .filter_map(|(hand, prob)| {
-                if hand[0].rank == ch_rank('A') && hand[1].rank == ch_rank('A') {
+                if hand[0].rank() == ch_rank('A') && hand[1].rank() == ch_rank('A') {
                     Some(prob)
                 } else {
                     None
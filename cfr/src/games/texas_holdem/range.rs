@@ -0,0 +1,143 @@
+//! Opponent hand ranges.
+//!
+//! A [`HandRange`] tracks which two-card combinations an opponent could still be
+//! holding given the public cards and action history, with a probability weight
+//! per combo. It is modeled on hanabi.rs's `CardPossibilityTable`: combinations
+//! are pruned as public information rules them out, and the helpers mirror its
+//! `color_determined` / `value_determined` shape (`is_single_combo`,
+//! `possible_combos`).
+
+use itertools::Itertools;
+use rand::Rng;
+use rand_distr::{
+    Distribution,
+    WeightedIndex,
+};
+
+use crate::games::PlayerId;
+
+use super::{
+    cards::Cards,
+    Card,
+    HandState,
+    PlayerState,
+    RootNodeSampler,
+    SubTreeId,
+};
+
+/// A weighted set of two-card combinations consistent with the public
+/// information observed so far.
+#[derive(Debug, Clone)]
+pub struct HandRange {
+    combos: Vec<([Card; 2], f64)>,
+}
+
+impl HandRange {
+    /// A uniform range over every two-card combination drawable from
+    /// `available`.
+    pub fn uniform(available: &Cards) -> Self {
+        let cards = available.to_vec();
+        let combos: Vec<Vec<Card>> = cards.into_iter().combinations(2).collect();
+        let weight = 1.0 / combos.len() as f64;
+        HandRange {
+            combos: combos.into_iter().map(|c| ([c[0], c[1]], weight)).collect(),
+        }
+    }
+
+    /// Builds a range from explicit `(combo, weight)` pairs (e.g. a solved
+    /// preflop range).
+    pub fn from_weighted(combos: Vec<([Card; 2], f64)>) -> Self {
+        HandRange {
+            combos,
+        }
+    }
+
+    /// The number of combos still in the range.
+    pub fn len(&self) -> usize {
+        self.combos.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.combos.is_empty()
+    }
+
+    /// Analogous to `CardPossibilityTable::{color,value}_determined`: true when
+    /// exactly one combination remains, so the opponent's hand is pinned down.
+    pub fn is_single_combo(&self) -> bool {
+        self.combos.len() == 1
+    }
+
+    /// Iterates the combinations still consistent with the observations.
+    pub fn possible_combos(&self) -> impl Iterator<Item = [Card; 2]> + '_ {
+        self.combos.iter().map(|(combo, _)| *combo)
+    }
+
+    /// Removes every combo that conflicts with a card no longer in the deck
+    /// (dealt to the board or another player), then renormalizes the weights.
+    pub fn prune(&mut self, available: &Cards) {
+        self.combos
+            .retain(|(combo, _)| available.contains(&combo[0]) && available.contains(&combo[1]));
+        self.normalize();
+    }
+
+    fn normalize(&mut self) {
+        let sum: f64 = self.combos.iter().map(|(_, w)| *w).sum();
+        if sum > 0.0 {
+            for (_, w) in &mut self.combos {
+                *w /= sum;
+            }
+        }
+    }
+
+    pub fn reach_probabilities(&self) -> Vec<f64> {
+        self.combos.iter().map(|(_, w)| *w).collect()
+    }
+}
+
+/// A [`RootNodeSampler`] backed by a [`HandRange`]: each sub-tree corresponds to
+/// one opponent combination, reached with that combo's weight.
+pub struct HandRangeSampler {
+    range: HandRange,
+    weights: Vec<f64>,
+    dist: WeightedIndex<f64>,
+    base_hand_state: HandState,
+    opponent_id: PlayerId,
+}
+
+impl HandRangeSampler {
+    pub fn new(range: HandRange, base_hand_state: HandState, opponent_id: PlayerId) -> Self {
+        let weights = range.reach_probabilities();
+        let dist = WeightedIndex::new(weights.clone()).unwrap();
+        HandRangeSampler {
+            range,
+            weights,
+            dist,
+            base_hand_state,
+            opponent_id,
+        }
+    }
+}
+
+impl RootNodeSampler for HandRangeSampler {
+    fn get_sub_tree_count(&self) -> usize {
+        self.range.len()
+    }
+
+    fn get_sub_tree_reach_probabilities(&self) -> &[f64] {
+        &self.weights
+    }
+
+    fn sample_sub_tree_id<R: Rng>(&self, rng: &mut R) -> SubTreeId {
+        self.dist.sample(rng)
+    }
+
+    fn get_hand_state_at_sub_tree_root(&self, id: SubTreeId) -> HandState {
+        let combo = self.range.possible_combos().nth(id).unwrap();
+        let mut hand_state = self.base_hand_state.clone();
+        if hand_state.players.len() <= self.opponent_id.index() {
+            hand_state.players.resize(self.opponent_id.index() + 1, PlayerState::default());
+        }
+        hand_state.players[self.opponent_id.index()].hole_cards = combo.to_vec();
+        hand_state
+    }
+}
@@ -1,3 +1,33 @@
+/// One discretized raise size. `PotFraction(f)` raises by `f` times the current
+/// pot on top of a call; `AllIn` shoves the whole stack.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BetSize {
+    PotFraction(f64),
+    AllIn,
+}
+
+/// The finite set of raise sizes a no-limit game tree branches on. Translating
+/// these pot-relative sizes into concrete `RaiseTo` amounts (see
+/// [`Dealer::legal_actions`](super::dealer::Dealer::legal_actions)) keeps the
+/// tree tunable without enumerating every integer raise.
+#[derive(Debug, Clone)]
+pub struct BetAbstraction {
+    pub sizes: Vec<BetSize>,
+}
+
+impl Default for BetAbstraction {
+    fn default() -> Self {
+        BetAbstraction {
+            sizes: vec![
+                BetSize::PotFraction(0.5),
+                BetSize::PotFraction(0.75),
+                BetSize::PotFraction(1.0),
+                BetSize::AllIn,
+            ],
+        }
+    }
+}
+
 // Note that it doesn't support limit holdem.
 #[derive(Debug, Clone)]
 pub struct Rule {
@@ -7,6 +37,12 @@ pub struct Rule {
     pub blinds: Vec<i32>,
     // Who plays first?
     pub first_player: Vec<usize>,
+    // Raise sizes the game tree is discretized into.
+    pub bet_abstraction: BetAbstraction,
+    // How many times to run the board out once everyone is all-in. `1` is the
+    // usual single runout; larger values settle each runout for `1/runouts` of
+    // the pot to cut all-in variance ("run it X times").
+    pub runouts: usize,
 }
 
 impl Rule {
@@ -18,6 +54,8 @@ impl Rule {
 
             blinds: vec![100, 50],
             first_player: vec![1, 0, 0, 0],
+            bet_abstraction: BetAbstraction::default(),
+            runouts: 1,
         }
     }
 
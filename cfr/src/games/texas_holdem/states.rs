@@ -12,6 +12,7 @@ use super::*;
 
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
 #[derive(Default)]
+#[derive(serde::Serialize, serde::Deserialize)]
 pub enum Round {
     #[default]
     Preflop,
@@ -74,6 +75,7 @@ impl Round {
 }
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(serde::Serialize, serde::Deserialize)]
 pub struct PlayerState {
     pub stack: i32,
     pub bet: i32,
@@ -117,6 +119,7 @@ impl Default for PlayerState {
 }
 
 #[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(serde::Serialize, serde::Deserialize)]
 pub struct RoundState {
     /// Minimum bet/raise amount. It includes bet size even acted in the previous round.
     /// For example, if
@@ -127,7 +130,26 @@ pub struct RoundState {
     pub bet_cnt: i32,
 }
 
+/// One entry in a hand's structured history. Unlike the scattered `debug!`/
+/// `info!` lines, these are typed and serializable, so a completed hand can be
+/// saved, diffed or fed to external analysis tools. Events are appended to
+/// [`HandState::events`] by [`Dealer::update`](super::dealer::Dealer::update)
+/// and the pot-resolving code as the hand plays out.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub enum HandEvent {
+    /// A player acted.
+    Action { player: usize, action: Action },
+    /// Betting moved on to the next round.
+    RoundTransition { round: Round },
+    /// The hand reached showdown with the given per-seat scores.
+    Showdown { hands: Vec<HandScore> },
+    /// Chips were awarded, as a per-seat net result.
+    PotAwarded { won_pots: Vec<i32> },
+}
+
 #[derive(Debug, Default, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(serde::Serialize, serde::Deserialize)]
 pub struct HandState {
     pub next_player: usize,
     pub last_action: Option<Action>,
@@ -138,6 +160,13 @@ pub struct HandState {
     pub community_cards: Vec<Card>,
 
     pub players: Vec<PlayerState>,
+
+    /// Structured, ordered log of what happened in this hand. Every
+    /// [`Dealer::update`](super::dealer::Dealer::update) appends to it, as does
+    /// the all-in runout. It takes part in the derived comparisons and hash like
+    /// every other field.
+    #[serde(default)]
+    pub events: Vec<HandEvent>,
 }
 
 impl HandState {
@@ -145,6 +174,27 @@ impl HandState {
         &self.players[self.next_player]
     }
 
+    /// The cards still in the deck, i.e. every card that has not been dealt as a
+    /// hole card or turned over on the board yet.
+    pub fn get_available_cards(&self) -> Cards {
+        let mut cards = Cards::new_all();
+        for card in &self.community_cards {
+            cards.pop(card);
+        }
+        for player in &self.players {
+            for card in &player.hole_cards {
+                cards.pop(card);
+            }
+        }
+        cards
+    }
+
+    /// The seat index of the first player (in dealing order) who has not yet
+    /// received hole cards, or `None` once every seat has been dealt.
+    pub fn next_undealt_player(&self) -> Option<usize> {
+        self.players.iter().position(|p| p.hole_cards.len() < 2)
+    }
+
     /// Returns the bet size which
     pub fn max_bet(&self) -> i32 {
         self.players.iter().fold(0, |a, p| cmp::max(a, p.bet))
@@ -217,34 +267,83 @@ impl HandState {
                 || (self.everyone_all_in() && self.community_cards.len() == 5)
         );
 
-        let mut scores = Vec::with_capacity(self.players.len());
-        let mut max_score = HandScore::fold();
+        let player_cnt = self.players.len();
+        let mut scores = Vec::with_capacity(player_cnt);
         for (i, player) in self.players.iter().enumerate() {
-            let score;
-            if !player.folded {
-                score = hands::calc_player_score(self, player);
+            let score = if !player.folded {
+                let score = hands::calc_player_score(self, player);
                 info!("  score@{}: {}", i, score);
-                max_score = max_score.max(score);
+                score
             } else {
-                score = HandScore::fold();
                 info!("  score@{}: fold", i);
-            }
+                HandScore::fold()
+            };
             scores.push(score);
         }
 
-        let mut won_pots = Vec::with_capacity(self.players.len());
-        let mut hands = Vec::with_capacity(self.players.len());
-        let winner_cnt = scores.iter().filter(|&a| *a == max_score).count();
-        let won_amount = self.pot() / winner_cnt as i32;
-        info!("  pot: {}, won: {}, winner_cnt: {}", self.pot(), won_amount, winner_cnt);
-        for (player, score) in self.players.iter().zip(scores.iter()) {
-            let won = if *score == max_score {
-                won_amount - player.bet
+        // Layered side pots: all-in players only contest the portion of the
+        // pot they actually matched. We peel the distinct bet levels off in
+        // ascending order; each level gap forms a sub-pot contested only by the
+        // (non-folded) players who contributed at least up to that level.
+        let mut won = vec![0i32; player_cnt];
+        let mut levels: Vec<i32> = self.players.iter().map(|p| p.bet).filter(|&b| b > 0).collect();
+        levels.sort_unstable();
+        levels.dedup();
+
+        let mut prev_level = 0;
+        for &level in &levels {
+            let layer_bet = level - prev_level;
+            // Every player whose contribution reaches this level pays `layer_bet`.
+            let sub_pot: i32 = self
+                .players
+                .iter()
+                .filter(|p| p.bet >= level)
+                .map(|_| layer_bet)
+                .sum();
+
+            // Only non-folded contributors at this level can win the layer.
+            let mut best = HandScore::fold();
+            for (i, p) in self.players.iter().enumerate() {
+                if !p.folded && p.bet >= level {
+                    best = best.max(scores[i]);
+                }
+            }
+            let winners: Vec<usize> = (0..player_cnt)
+                .filter(|&i| {
+                    !self.players[i].folded && self.players[i].bet >= level && scores[i] == best
+                })
+                .collect();
+
+            if !winners.is_empty() {
+                let share = sub_pot / winners.len() as i32;
+                let mut remainder = sub_pot - share * winners.len() as i32;
+                for &i in &winners {
+                    won[i] += share;
+                    // Integer remainder goes to the earliest eligible seat(s).
+                    if remainder > 0 {
+                        won[i] += 1;
+                        remainder -= 1;
+                    }
+                }
             } else {
-                0 - player.bet
-            };
-            won_pots.push(won);
-            hands.push(*score);
+                // No non-folded player reached this layer: it is an uncalled
+                // bet, returned to whoever put the chips in. Rare in normal
+                // betting, but otherwise these chips would vanish from the pot.
+                for (i, p) in self.players.iter().enumerate() {
+                    if p.bet >= level {
+                        won[i] += layer_bet;
+                    }
+                }
+            }
+            prev_level = level;
+        }
+
+        info!("  pot: {}, side-pot levels: {:?}", self.pot(), levels);
+        let mut won_pots = Vec::with_capacity(player_cnt);
+        let mut hands = Vec::with_capacity(player_cnt);
+        for (i, player) in self.players.iter().enumerate() {
+            won_pots.push(won[i] - player.bet);
+            hands.push(scores[i]);
         }
         HandResult {
             won_pots,
@@ -268,9 +367,17 @@ impl HandState {
         }
         s
     }
+
+    /// Emits this hand's structured event log as pretty JSON, for saving
+    /// training trajectories, diffing solver runs or feeding the hand to
+    /// external analysis tools instead of scraping the `debug!`/`info!` lines.
+    pub fn events_to_json(&self) -> String {
+        serde_json::to_string_pretty(&self.events).unwrap()
+    }
 }
 
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(serde::Serialize, serde::Deserialize)]
 pub enum Action {
     Fold,
     Call,
@@ -0,0 +1,148 @@
+//! JSON export/import of solved strategies and game-tree replays.
+//!
+//! A solved [`Strategy`] is a mapping from information sets to action
+//! probabilities, but that table lives only in memory. This module serializes
+//! it (together with a depth-first replay of the game tree) so a policy can be
+//! re-evaluated with [`compute_exploitability`](crate::eval::compute_exploitability)
+//! without retraining, and inspected by external tools.
+
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::{
+        BufReader,
+        BufWriter,
+    },
+    path::Path,
+};
+
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use serde_json::json;
+
+use crate::{
+    eval::Strategy,
+    games::{
+        Game,
+        PlayerId,
+    },
+};
+
+/// A single information set entry: the legal action labels and the averaged
+/// action probabilities returned by the strategy.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InfoSetEntry {
+    pub actions: Vec<String>,
+    pub probabilities: Vec<f64>,
+}
+
+/// A solved strategy serialized by its information-set `Display` keys.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StrategyDoc {
+    pub info_sets: BTreeMap<String, InfoSetEntry>,
+}
+
+fn collect_info_sets<G, St>(
+    game: &G,
+    strategy: &St,
+    state: &G::State,
+    doc: &mut StrategyDoc,
+    replay: &mut serde_json::Value,
+) where
+    G: Game,
+    St: Strategy<G>,
+{
+    if game.is_terminal(state) {
+        *replay = json!({
+            "state": format!("{:?}", state),
+            "terminal": true,
+            "payouts": game.get_payouts(state),
+        });
+        return;
+    }
+
+    let player = game.get_node_player_id(state);
+    if player == PlayerId::Chance {
+        let actions = game.list_legal_chance_actions(state);
+        let mut children = vec![];
+        for (act, prob) in actions {
+            let mut child = serde_json::Value::Null;
+            collect_info_sets(game, strategy, &game.with_action(state, act), doc, &mut child);
+            children.push(json!({ "action": format!("{}", act), "prob": prob, "child": child }));
+        }
+        *replay = json!({ "state": format!("{:?}", state), "chance": children });
+        return;
+    }
+
+    let info_set = game.to_info_set(state);
+    let actions = game.list_legal_actions(state);
+    let probabilities = strategy.safe_get_strategy(actions.len(), &info_set);
+    doc.info_sets.entry(format!("{}", info_set)).or_insert_with(|| InfoSetEntry {
+        actions: actions.iter().map(|a| format!("{}", a)).collect(),
+        probabilities: probabilities.clone(),
+    });
+
+    let mut children = vec![];
+    for act in &actions {
+        let mut child = serde_json::Value::Null;
+        collect_info_sets(game, strategy, &game.with_action(state, *act), doc, &mut child);
+        children.push(json!({ "action": format!("{}", act), "child": child }));
+    }
+    *replay = json!({
+        "state": format!("{:?}", state),
+        "player": player.index(),
+        "children": children,
+    });
+}
+
+/// Serializes a solved `strategy` and a replay of the whole game tree to
+/// `path` as a JSON document.
+pub fn export_strategy<G, St, P>(game: &G, strategy: &St, path: P)
+where
+    G: Game,
+    St: Strategy<G>,
+    P: AsRef<Path>,
+{
+    let root = game.new_root();
+    let mut doc = StrategyDoc::default();
+    let mut replay = serde_json::Value::Null;
+    collect_info_sets(game, strategy, &root, &mut doc, &mut replay);
+
+    let f = File::create(path.as_ref()).unwrap_or_else(|err| {
+        panic!("Failed to create a file: {:?}, {}", path.as_ref(), err);
+    });
+    let w = BufWriter::new(f);
+    serde_json::to_writer_pretty(w, &json!({ "strategy": doc, "replay": replay }))
+        .expect("Failed to write JSON");
+}
+
+/// A read-only strategy reconstructed from a [`StrategyDoc`].
+///
+/// Looks info sets up by their `Display` string, so it can be fed straight back
+/// into `compute_exploitability`.
+pub struct LoadedStrategy {
+    doc: StrategyDoc,
+}
+
+impl LoadedStrategy {
+    pub fn load<P: AsRef<Path>>(path: P) -> Self {
+        let f = File::open(path.as_ref()).unwrap_or_else(|err| {
+            panic!("Failed to open a file: {:?}, {}", path.as_ref(), err);
+        });
+        let r = BufReader::new(f);
+        let value: serde_json::Value = serde_json::from_reader(r).expect("Failed to read JSON");
+        let doc: StrategyDoc =
+            serde_json::from_value(value["strategy"].clone()).expect("Invalid strategy document");
+        LoadedStrategy {
+            doc,
+        }
+    }
+}
+
+impl<G: Game> Strategy<G> for LoadedStrategy {
+    fn get_strategy(&self, info_set: &<G as Game>::InfoSet) -> Option<Vec<f64>> {
+        self.doc.info_sets.get(&format!("{}", info_set)).map(|e| e.probabilities.clone())
+    }
+}
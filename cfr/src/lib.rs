@@ -1,5 +1,10 @@
+pub mod cards;
 pub mod eval;
+pub mod evaluate;
 pub mod games;
+pub mod io;
+pub mod replay;
+pub mod sim;
 
 use std::{
     collections::HashMap,
@@ -35,16 +40,29 @@ pub struct TrainingArgs {
     #[clap(long, short, value_parser, default_value_t = 1000)]
     iterations: usize,
 
+    /// If set, ignore `iterations` and train until this many seconds of
+    /// wall-clock time have elapsed, returning whatever average strategy has
+    /// converged by the deadline.
+    #[clap(long, value_parser)]
+    train_secs: Option<u64>,
+
     #[clap(long, short, value_parser, value_hint(ValueHint::FilePath))]
     log_path: Option<PathBuf>,
 }
 
-#[derive(Clone)]
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+#[serde(bound(
+    serialize = "S::InfoSet: serde::Serialize, S::Action: serde::Serialize",
+    deserialize = "S::InfoSet: serde::Deserialize<'de>, S::Action: serde::Deserialize<'de>"
+))]
 pub struct Node<S>
 where
     S: State,
 {
     regret_sum: Vec<f64>,
+    // Transient per-iteration strategy; it is recomputed from `regret_sum` on
+    // the next traversal, so it is not persisted.
+    #[serde(skip)]
     strategy: Vec<f64>,
     strategy_sum: Vec<f64>,
 
@@ -257,7 +275,7 @@ where
             }
         }
 
-        let opponent = player.opponent();
+        let opponent = player.next_player(2);
         let node = self.nodes.get_mut(&info_set).unwrap();
         for (i, action_util) in player_action_utils.iter().enumerate() {
             let regret: f64 = action_util - node_util[player.index()];
@@ -268,7 +286,52 @@ where
         node_util
     }
 
+    /// The solved blueprint as an info-set → average-strategy table, suitable
+    /// for feeding to a `BlueprintAgent` in the evaluation harness.
+    pub fn blueprint(&self) -> HashMap<S::InfoSet, Vec<f64>> {
+        self.nodes.iter().map(|(k, n)| (k.clone(), n.to_average_strategy())).collect()
+    }
+
+    /// Serializes every node's info set, cumulative regret and strategy sums to
+    /// `path` as JSON. Nodes are written in sorted info-set order so checkpoints
+    /// diff cleanly across runs. The average strategy is recovered from
+    /// `strategy_sum` on load, so a solved blueprint can be checkpointed and
+    /// resumed or inspected without retraining.
+    pub fn save_to_json(&self, path: impl AsRef<std::path::Path>) -> std::io::Result<()>
+    where
+        S::InfoSet: serde::Serialize,
+        S::Action: serde::Serialize,
+    {
+        let mut nodes: Vec<&Node<S>> = self.nodes.values().collect();
+        nodes.sort();
+        let file = std::fs::File::create(path)?;
+        serde_json::to_writer_pretty(file, &nodes)?;
+        Ok(())
+    }
+
+    /// Rebuilds a trainer from a blueprint previously written by
+    /// [`Self::save_to_json`]. Training can continue from the loaded
+    /// `regret_sum`/`strategy_sum`, and the per-iteration `strategy` buffer is
+    /// lazily re-initialized on the next traversal.
+    pub fn load_from_json(path: impl AsRef<std::path::Path>) -> std::io::Result<Self>
+    where
+        S::InfoSet: for<'de> serde::Deserialize<'de>,
+        S::Action: for<'de> serde::Deserialize<'de>,
+    {
+        let file = std::fs::File::open(path)?;
+        let nodes: Vec<Node<S>> = serde_json::from_reader(file)?;
+        let nodes = nodes.into_iter().map(|n| (n.info_set.clone(), n)).collect();
+        Ok(Trainer {
+            nodes,
+        })
+    }
+
     pub fn train(&mut self, args: &TrainingArgs) {
+        if let Some(secs) = args.train_secs {
+            self.train_until(Duration::from_secs(secs));
+            return;
+        }
+
         let mut util = 0.0;
         let mut timer = Instant::now();
         for i in 0..args.iterations {
@@ -281,7 +344,51 @@ where
             }
         }
         info!("Training has finished");
+        self.report_nodes(util / args.iterations as f64);
+    }
+
+    /// Anytime training: iterate CFR until `deadline` of wall-clock time has
+    /// elapsed, then stop. Because the average strategy is recomputed from the
+    /// accumulated `strategy_sum` on demand, the trainer holds a usable strategy
+    /// at any stopping point. Progress (iterations-per-second and the running
+    /// average game value) is logged periodically.
+    pub fn train_until(&mut self, deadline: Duration) {
+        let start = Instant::now();
+        let mut util = 0.0;
+        let mut iterations: u64 = 0;
+        let mut timer = Instant::now();
+        while start.elapsed() < deadline {
+            let initial = <S as State>::new_root();
+            util += self.cfr(&initial, [1.0, 1.0])[PlayerId::Player(0).index()];
+            iterations += 1;
+            if timer.elapsed() > Duration::from_secs(2) {
+                let elapsed = start.elapsed().as_secs_f64();
+                info!(
+                    "{:6.1}s: {:10} iters ({:.0} it/s), average game value: {}",
+                    elapsed,
+                    iterations,
+                    iterations as f64 / elapsed,
+                    util / iterations as f64
+                );
+                timer = Instant::now();
+            }
+        }
+        info!(
+            "Anytime training has finished after {} iterations in {:.1}s",
+            iterations,
+            start.elapsed().as_secs_f64()
+        );
+        let avg = if iterations > 0 {
+            util / iterations as f64
+        } else {
+            0.0
+        };
+        self.report_nodes(avg);
+    }
 
+    /// Logs the sorted node table and summary statistics shared by both
+    /// training modes.
+    fn report_nodes(&self, average_game_value: f64) {
         let mut nodes: Vec<Node<S>> = self.nodes.values().cloned().collect();
         nodes.sort();
         info!("Nodes [");
@@ -291,7 +398,7 @@ where
         info!("]");
 
         info!("# of infoset: {}", self.nodes.len());
-        info!("Average game value: {}", util / args.iterations as f64);
+        info!("Average game value: {}", average_game_value);
         info!("exploitability: {}", compute_exploitability(self));
     }
 }
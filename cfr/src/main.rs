@@ -20,10 +20,17 @@ use clap::{
 
 use cfr::{
     eval::compute_exploitability,
+    io,
+    sim::{
+        self,
+        Participant,
+    },
     games::{
+        coinche::Coinche,
         dudo::Dudo,
         kuhn::Kuhn,
         leduc::Leduc,
+        rps::Rps,
         Game,
     },
     solvers::{
@@ -53,12 +60,43 @@ struct TrainingArgs {
 
     #[clap(long, short, value_parser, value_hint(ValueHint::FilePath))]
     log_path: Option<PathBuf>,
+
+    /// Serialize the solved strategy and a game-tree replay to this path as JSON.
+    #[clap(long, value_parser, value_hint(ValueHint::FilePath))]
+    out_json: Option<PathBuf>,
 }
 
 #[derive(Subcommand)]
 pub enum SolverArg {
     Cfr(solvers::cfr::SolverArgs),
     MccfrExternalSampling(solvers::mccfr_external_sampling::SolverArgs),
+    QLearning(solvers::qlearning::SolverArgs),
+    Genetic(solvers::genetic::SolverArgs),
+    Mcts(solvers::mcts::SolverArgs),
+    Simulate(SimulateArgs),
+}
+
+/// Plays a saved policy (see `--out-json`) out for many games and reports the
+/// empirical returns per seat.
+#[derive(Args)]
+pub struct SimulateArgs {
+    /// Policy played at seat 0 (a JSON file produced by `--out-json`).
+    #[clap(long, value_parser, value_hint(ValueHint::FilePath))]
+    policy0: PathBuf,
+
+    /// Policy played at seat 1. Defaults to `policy0` (self-play).
+    #[clap(long, value_parser, value_hint(ValueHint::FilePath))]
+    policy1: Option<PathBuf>,
+
+    #[clap(long, short, value_parser, default_value_t = 10000)]
+    num_games: usize,
+
+    #[clap(long, short, value_parser, default_value_t = 42)]
+    seed: u64,
+
+    /// Ask a human for the actions of this seat instead of sampling a policy.
+    #[clap(long, value_parser)]
+    interactive_seat: Option<usize>,
 }
 
 #[derive(Debug, Clone, Copy, ValueEnum)]
@@ -66,6 +104,38 @@ pub enum GameType {
     Kuhn,
     Dudo,
     Leduc,
+    Rps,
+    Coinche,
+}
+
+fn run_simulate<G: Game>(game: G, args: SimulateArgs) {
+    use rand::SeedableRng;
+    use wyhash::WyRng;
+
+    let policy0 = io::LoadedStrategy::load(&args.policy0);
+    let policy1 = args.policy1.as_ref().map(io::LoadedStrategy::load);
+    let policy1_ref: &io::LoadedStrategy = policy1.as_ref().unwrap_or(&policy0);
+
+    let mut participants: Vec<Participant<G>> =
+        vec![Participant::Strategy(&policy0), Participant::Strategy(policy1_ref)];
+    if let Some(seat) = args.interactive_seat {
+        participants[seat] = Participant::Human;
+    }
+
+    let mut rng = WyRng::seed_from_u64(args.seed);
+    let stats = sim::simulate(&game, &participants, args.num_games, &mut rng);
+    for (seat, s) in stats.iter().enumerate() {
+        let (lo, hi) = s.confidence_interval_95();
+        info!(
+            "seat {}: mean={:.4} +/- {:.4} (95% CI [{:.4}, {:.4}]), win_rate={:.4}",
+            seat,
+            s.mean_payoff,
+            s.std_error(),
+            lo,
+            hi,
+            s.win_rate()
+        );
+    }
 }
 
 fn run<G, S>(game: G, training_args: TrainingArgs, solver_args: S::SolverArgs)
@@ -82,6 +152,7 @@ where
     G: Game,
     S: Solver<G>,
 {
+    let out_json = args.out_json.clone();
     let mut log_writer = if let Some(path) = args.log_path {
         let f = File::create(path.clone()).unwrap_or_else(|err| {
             panic!("Failed to create a file: {:?}, {}", path, err);
@@ -156,6 +227,11 @@ where
 
     info!("Average game value: {}", util / i as f64);
     info!("exploitability: {}", exploitability);
+
+    if let Some(path) = out_json {
+        info!("Writing solved strategy to {:?}", path);
+        io::export_strategy(solver.game_ref(), solver, path);
+    }
 }
 
 macro_rules! def_solver {
@@ -164,6 +240,8 @@ macro_rules! def_solver {
             GameType::Kuhn => run::<Kuhn, $solver_t>(Kuhn::new(), $($solver_args),+),
             GameType::Dudo => run::<Dudo, $solver_t>(Dudo::new(), $($solver_args),+),
             GameType::Leduc => run::<Leduc, $solver_t>(Leduc::new(), $($solver_args),+),
+            GameType::Rps => run::<Rps, $solver_t>(Rps::new(), $($solver_args),+),
+            GameType::Coinche => run::<Coinche, $solver_t>(Coinche::new(), $($solver_args),+),
         };
     };
 }
@@ -187,5 +265,21 @@ fn main() {
                 solver_args
             );
         }
+        SolverArg::QLearning(solver_args) => {
+            def_solver!(solvers::qlearning::Trainer<_>, args.game, args.training_args, solver_args);
+        }
+        SolverArg::Genetic(solver_args) => {
+            def_solver!(solvers::genetic::Trainer<_>, args.game, args.training_args, solver_args);
+        }
+        SolverArg::Mcts(solver_args) => {
+            def_solver!(solvers::mcts::Trainer<_>, args.game, args.training_args, solver_args);
+        }
+        SolverArg::Simulate(sim_args) => match args.game {
+            GameType::Kuhn => run_simulate(Kuhn::new(), sim_args),
+            GameType::Dudo => run_simulate(Dudo::new(), sim_args),
+            GameType::Leduc => run_simulate(Leduc::new(), sim_args),
+            GameType::Rps => run_simulate(Rps::new(), sim_args),
+            GameType::Coinche => run_simulate(Coinche::new(), sim_args),
+        },
     }
 }
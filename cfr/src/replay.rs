@@ -0,0 +1,87 @@
+//! JSON trajectory export for a concrete line of play.
+//!
+//! Where [`io`](crate::io) serializes a whole solved strategy, this module
+//! captures a single played-out hand: the sequence of decision and chance
+//! nodes, the info set and legal actions at each, the action taken, and the
+//! terminal payouts. The resulting [`GameLog`] is `serde`-serializable, so a
+//! trajectory can be written to JSON and fed to an external viewer or diffed
+//! between solver runs.
+
+use std::{
+    fs::File,
+    io::BufWriter,
+    path::Path,
+};
+
+use serde::Serialize;
+
+use crate::games::{
+    Game,
+    PlayerId,
+};
+
+/// One node along a played-out line. Chance nodes leave `player`/`info_set`
+/// unset; player nodes record the acting seat and its information set.
+#[derive(Debug, Clone, Serialize)]
+pub struct GameStep {
+    pub player: Option<usize>,
+    pub info_set: Option<String>,
+    pub legal_actions: Vec<String>,
+    pub action: String,
+    /// Debug rendering of the resulting state (bets, dice, board, ...).
+    pub state: String,
+}
+
+/// A full trajectory: the ordered steps plus the terminal payouts.
+#[derive(Debug, Clone, Serialize)]
+pub struct GameLog {
+    pub steps: Vec<GameStep>,
+    pub payouts: Vec<f64>,
+}
+
+/// Drives `game` through `actions` from the root and records each step into a
+/// [`GameLog`]. `actions` is the full line including chance deals, applied in
+/// order; it must reach a terminal state exactly.
+pub fn play_and_log<G: Game>(game: &G, actions: &[G::Action]) -> GameLog {
+    let mut state = game.new_root();
+    let mut steps = Vec::with_capacity(actions.len());
+    let mut pending = actions.iter();
+
+    while !game.is_terminal(&state) {
+        let player = game.get_node_player_id(&state);
+        let act = *pending.next().expect("action list exhausted before a terminal state");
+
+        let (who, info_set, legal_actions) = if player == PlayerId::Chance {
+            let legal =
+                game.list_legal_chance_actions(&state).iter().map(|(a, _)| a.to_string()).collect();
+            (None, None, legal)
+        } else {
+            let info_set = game.to_info_set(&state);
+            let legal = game.list_legal_actions(&state).iter().map(|a| a.to_string()).collect();
+            (Some(player.index()), Some(info_set.to_string()), legal)
+        };
+
+        state = game.with_action(&state, act);
+        steps.push(GameStep {
+            player: who,
+            info_set,
+            legal_actions,
+            action: act.to_string(),
+            state: format!("{:?}", state),
+        });
+    }
+
+    GameLog {
+        steps,
+        payouts: game.get_payouts(&state),
+    }
+}
+
+/// Writes a [`GameLog`] to `path` as pretty-printed JSON.
+pub fn write_log<P: AsRef<Path>>(log: &GameLog, path: P) {
+    let f = File::create(path.as_ref()).unwrap_or_else(|err| {
+        panic!("Failed to create a file: {:?}, {}", path.as_ref(), err);
+    });
+    let w = BufWriter::new(f);
+    serde_json::to_writer_pretty(w, log).expect("Failed to write JSON");
+}
@@ -0,0 +1,210 @@
+//! Monte-Carlo match simulation.
+//!
+//! Plays full games to terminal by sampling from a strategy at player nodes and
+//! with [`Game::sample_chance_action`] at chance nodes, then aggregates the
+//! realized returns. This complements the exact
+//! [`compute_exploitability`](crate::eval::compute_exploitability) number with an
+//! empirical, head-to-head measurement.
+
+use std::io::{
+    self,
+    Write,
+};
+
+use rand_distr::{
+    Distribution,
+    WeightedIndex,
+};
+
+use rand::{
+    Rng,
+    SeedableRng,
+};
+use wyhash::WyRng;
+
+use crate::{
+    eval::Strategy,
+    games::{
+        Game,
+        PlayerId,
+    },
+};
+
+/// One seat in a simulated match.
+pub enum Participant<'a, G: Game> {
+    /// Samples actions from the given strategy's average distribution.
+    Strategy(&'a dyn Strategy<G>),
+    /// Prompts for actions on stdin/stdout.
+    Human,
+}
+
+/// Aggregated statistics for a single seat over many playouts.
+#[derive(Debug, Clone)]
+pub struct SeatStats {
+    pub games: usize,
+    pub mean_payoff: f64,
+    pub variance: f64,
+    pub wins: usize,
+}
+
+impl SeatStats {
+    /// The standard error of the mean payoff.
+    pub fn std_error(&self) -> f64 {
+        if self.games == 0 {
+            return 0.0;
+        }
+        (self.variance / self.games as f64).sqrt()
+    }
+
+    /// A 95% confidence interval for the mean payoff (≈1.96 standard errors).
+    pub fn confidence_interval_95(&self) -> (f64, f64) {
+        let half = 1.96 * self.std_error();
+        (self.mean_payoff - half, self.mean_payoff + half)
+    }
+
+    pub fn win_rate(&self) -> f64 {
+        if self.games == 0 {
+            return 0.0;
+        }
+        self.wins as f64 / self.games as f64
+    }
+}
+
+fn sample_action<R: Rng>(rng: &mut R, probs: &[f64]) -> usize {
+    let dist = WeightedIndex::new(probs).unwrap_or_else(|e| {
+        panic!("Invalid weights: e: {} probs: {:?}", e, probs);
+    });
+    dist.sample(rng)
+}
+
+fn ask_human<G: Game>(game: &G, state: &G::State, actions: &[G::Action]) -> usize {
+    println!("Your turn. Info set: {}", game.to_info_set(state));
+    for (i, act) in actions.iter().enumerate() {
+        println!("  [{}] {}", i, act);
+    }
+    loop {
+        print!("Choose an action index: ");
+        io::stdout().flush().ok();
+        let mut line = String::new();
+        io::stdin().read_line(&mut line).expect("Failed to read stdin");
+        match line.trim().parse::<usize>() {
+            Ok(i) if i < actions.len() => return i,
+            _ => println!("Invalid choice, try again."),
+        }
+    }
+}
+
+fn play_one<G, R>(
+    game: &G,
+    participants: &[Participant<G>],
+    rng: &mut R,
+) -> Vec<f64>
+where
+    G: Game,
+    R: Rng,
+{
+    let mut state = game.new_root();
+    while !game.is_terminal(&state) {
+        let player = game.get_node_player_id(&state);
+        if player == PlayerId::Chance {
+            let action = game.sample_chance_action(rng, &state);
+            state = game.with_action(&state, action);
+            continue;
+        }
+        let actions = game.list_legal_actions(&state);
+        let index = match &participants[player.index()] {
+            Participant::Strategy(strategy) => {
+                let probs = strategy.safe_get_strategy(actions.len(), &game.to_info_set(&state));
+                sample_action(rng, &probs)
+            }
+            Participant::Human => ask_human(game, &state, &actions),
+        };
+        state = game.with_action(&state, actions[index]);
+    }
+    game.get_payouts(&state)
+}
+
+/// Plays `num_games` matches between the given seat participants and returns the
+/// per-seat statistics.
+pub fn simulate<G, R>(
+    game: &G,
+    participants: &[Participant<G>],
+    num_games: usize,
+    rng: &mut R,
+) -> Vec<SeatStats>
+where
+    G: Game,
+    R: Rng,
+{
+    // Online mean/variance via Welford's algorithm.
+    let num_players = game.player_count();
+    let mut mean = vec![0.0f64; num_players];
+    let mut m2 = vec![0.0f64; num_players];
+    let mut wins = vec![0usize; num_players];
+    for g in 0..num_games {
+        let payouts = play_one(game, participants, rng);
+        let n = (g + 1) as f64;
+        for p in 0..num_players {
+            let delta = payouts[p] - mean[p];
+            mean[p] += delta / n;
+            m2[p] += delta * (payouts[p] - mean[p]);
+            if payouts[p] > 0.0 {
+                wins[p] += 1;
+            }
+        }
+    }
+    (0..num_players)
+        .map(|p| SeatStats {
+            games: num_games,
+            mean_payoff: mean[p],
+            variance: if num_games > 1 {
+                m2[p] / (num_games as f64 - 1.0)
+            } else {
+                0.0
+            },
+            wins: wins[p],
+        })
+        .collect()
+}
+
+/// A seed-swept head-to-head evaluator.
+///
+/// Pits two [`Strategy`] implementations against each other over a fixed number
+/// of seeded playouts and reports each seat's mean realized payoff with its
+/// standard error and 95% confidence interval. Fixing `seed` makes the whole
+/// sweep reproducible, and [`Self::reproduce_recipe`] renders the flags that
+/// re-run it — the same `-n N -s S` recipe the Hanabi simulator prints.
+pub struct Simulator<G: Game> {
+    num_games: usize,
+    seed: u64,
+    _game: std::marker::PhantomData<G>,
+}
+
+impl<G: Game> Simulator<G> {
+    pub fn new(num_games: usize, seed: u64) -> Self {
+        Simulator {
+            num_games,
+            seed,
+            _game: std::marker::PhantomData,
+        }
+    }
+
+    /// Plays `num_games` full games of `game` with `strategy0` in seat 0 and
+    /// `strategy1` in seat 1, returning the per-seat statistics.
+    pub fn run(
+        &self,
+        game: &G,
+        strategy0: &dyn Strategy<G>,
+        strategy1: &dyn Strategy<G>,
+    ) -> Vec<SeatStats> {
+        let participants =
+            [Participant::Strategy(strategy0), Participant::Strategy(strategy1)];
+        let mut rng = WyRng::seed_from_u64(self.seed);
+        simulate(game, &participants, self.num_games, &mut rng)
+    }
+
+    /// The command-line flags needed to reproduce this sweep.
+    pub fn reproduce_recipe(&self) -> String {
+        format!("-n {} -s {}", self.num_games, self.seed)
+    }
+}
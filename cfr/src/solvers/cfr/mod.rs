@@ -1,28 +1,48 @@
 pub mod node;
 
-use std::{cell::RefCell, collections::HashMap, rc::Rc};
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+};
 
 use crate::{eval::Strategy, games::Game};
 use clap::Args;
 use log::{debug, info};
 use more_asserts::assert_gt;
 use node::Node;
+use rand::Rng;
+use rand_distr::{Distribution, WeightedIndex};
 
 use crate::games::PlayerId;
 
 use super::Solver;
 
+/// A node shared across worker threads. Each info set owns its own lock so
+/// threads that reach different info sets never contend, while threads that
+/// reach the same info set serialize their regret accumulation.
+type SharedNode<G> = Arc<Mutex<Node<G>>>;
+type NodeStore<G> = Arc<Mutex<HashMap<<G as Game>::InfoSet, SharedNode<G>>>>;
+
 #[derive(Args)]
-pub struct SolverArgs {}
+pub struct SolverArgs {
+    /// Number of worker threads used to traverse the root's independent
+    /// chance/sub-tree children. `1` keeps the single-threaded traversal, whose
+    /// results are bit-for-bit identical to the serial solver.
+    #[arg(long, default_value_t = 1)]
+    threads: usize,
+}
 
 pub struct Trainer<G>
 where
     G: Game,
 {
     game: G,
-    //nodes: HashMap<G::InfoSet, Node<G>>,
-    nodes: Rc<RefCell<HashMap<G::InfoSet, Rc<RefCell<Node<G>>>>>>,
-    touched_nodes_count: usize,
+    nodes: NodeStore<G>,
+    touched_nodes_count: AtomicUsize,
+    threads: usize,
 }
 
 impl<G> Trainer<G>
@@ -30,36 +50,53 @@ where
     G: Game,
 {
     #[cfg(test)]
-    pub fn new_with_nodes(
-        game: G,
-        _args: SolverArgs,
-        nodes: Rc<RefCell<HashMap<G::InfoSet, Rc<RefCell<Node<G>>>>>>,
-    ) -> Self {
+    pub fn new_with_nodes(game: G, _args: SolverArgs, nodes: NodeStore<G>) -> Self {
         Trainer {
             game,
             nodes,
-            touched_nodes_count: 0,
+            touched_nodes_count: AtomicUsize::new(0),
+            threads: 1,
         }
     }
 
-    pub fn cfr(&mut self, state: &G::State, actions_prob: [f64; 2]) -> [f64; 2] {
-        self.touched_nodes_count += 1;
+    /// Fetches (or lazily creates) the shared node for `state`'s info set. The
+    /// map lock is held only for the lookup/insert, never across recursion.
+    fn node_for(&self, state: &G::State) -> SharedNode<G> {
+        let info_set = self.game.to_info_set(state);
+        let mut map = self.nodes.lock().unwrap();
+        Arc::clone(map.entry(info_set.clone()).or_insert_with(|| {
+            let actions = self.game.list_legal_actions(state);
+            Arc::new(Mutex::new(Node::new(actions, info_set)))
+        }))
+    }
+
+    /// Counterfactual regret minimization over a general-sum game with an
+    /// arbitrary number of players. `reach_probs[p]` is the probability player
+    /// `p` plays to reach `state` under the current strategy profile, and
+    /// `chance_reach` is the probability nature's moves along the way produced
+    /// this history. The recursion returns the per-player utility vector of
+    /// `state`; the counterfactual weight on a node's regret is the product of
+    /// every *other* player's reach probability times `chance_reach`, which
+    /// reduces to the opponent's reach times `chance_reach` in the heads-up case.
+    ///
+    /// Chance is tracked in its own slot rather than folded into each player's
+    /// reach, so it is multiplied into the counterfactual weight exactly once
+    /// instead of `num_players - 1` times.
+    pub fn cfr(&self, state: &G::State, reach_probs: &[f64], chance_reach: f64) -> Vec<f64> {
+        self.touched_nodes_count.fetch_add(1, Ordering::Relaxed);
 
         if self.game.is_terminal(state) {
             return self.game.get_payouts(state);
         }
 
+        let num_players = reach_probs.len();
         let player = self.game.get_node_player_id(state);
         if player == PlayerId::Chance {
             let actions = self.game.list_legal_chance_actions(state);
-            let mut node_util = [0.0, 0.0];
+            let mut node_util = vec![0.0; num_players];
             for (act, prob) in actions {
                 let next_state = self.game.with_action(state, act);
-                let mut next_actions_prob = actions_prob;
-                for action_prob in &mut next_actions_prob {
-                    *action_prob *= prob;
-                }
-                let action_util = self.cfr(&next_state, next_actions_prob);
+                let action_util = self.cfr(&next_state, reach_probs, chance_reach * prob);
                 for (player, player_action_util) in action_util.iter().enumerate() {
                     node_util[player] += prob * player_action_util;
                 }
@@ -67,81 +104,239 @@ where
             return node_util;
         }
 
-        let info_set = self.game.to_info_set(state);
-        let node = Rc::clone(
-            self.nodes.borrow_mut().entry(self.game.to_info_set(state)).or_insert_with(|| {
-                let actions = self.game.list_legal_actions(state);
-                let node = Node::new(actions, info_set.clone());
-                Rc::new(RefCell::new(node))
-            }),
-        );
-        let mut node_mut = node.borrow_mut();
-        let mut node_util = [0.0f64; 2];
-
-        let actions_len = node_mut.get_actions().len();
-        assert_gt!(actions_len, 0);
-        debug!("CFR state: {:#?}", state);
-        debug!("legal actions: {:#?}", node_mut.get_actions());
-
-        let mut player_action_utils = vec![0.0; actions_len]; // Note: allocating array on the stack is faster.
-        let realization_weight = actions_prob[player.index()];
-        node_mut.regret_matching(realization_weight);
-        let strategy = node_mut.get_strategy();
-        for (i, act) in node_mut.get_actions().iter().enumerate() {
+        let node = self.node_for(state);
+
+        // Read the current strategy under the lock, then release it while we
+        // recurse so sibling threads can make progress on other info sets.
+        let (actions, strategy) = {
+            let mut node_mut = node.lock().unwrap();
+            let actions_len = node_mut.get_actions().len();
+            assert_gt!(actions_len, 0);
+            debug!("CFR state: {:#?}", state);
+            debug!("legal actions: {:#?}", node_mut.get_actions());
+
+            let realization_weight = reach_probs[player.index()];
+            node_mut.regret_matching(realization_weight);
+            (node_mut.get_actions().to_vec(), node_mut.get_strategy().to_vec())
+        };
+
+        let mut node_util = vec![0.0f64; num_players];
+        let mut player_action_utils = vec![0.0; actions.len()];
+        for (i, act) in actions.iter().enumerate() {
             let action_prob = strategy[i];
             let next_state = self.game.with_action(state, *act);
-            let mut next_actions_prob = actions_prob;
+            let mut next_reach_probs = reach_probs.to_vec();
 
-            next_actions_prob[player.index()] *= action_prob;
+            next_reach_probs[player.index()] *= action_prob;
 
-            let action_util = self.cfr(&next_state, next_actions_prob);
+            let action_util = self.cfr(&next_state, &next_reach_probs, chance_reach);
             player_action_utils[i] = action_util[player.index()];
             for (player, action_util) in action_util.iter().enumerate() {
                 node_util[player] += action_prob * action_util;
             }
         }
 
-        let opponent = player.opponent();
-        for (i, action_util) in player_action_utils.iter().enumerate() {
-            let regret: f64 = action_util - node_util[player.index()];
-            let opponent_prob = actions_prob[opponent.index()];
-            node_mut.add_regret_sum(i, regret, opponent_prob);
+        // Counterfactual reach: the product of all players' reach probabilities
+        // except the acting player's, times the chance reach (counted once).
+        let counterfactual_reach: f64 = chance_reach
+            * reach_probs
+                .iter()
+                .enumerate()
+                .filter(|(p, _)| *p != player.index())
+                .map(|(_, prob)| *prob)
+                .product::<f64>();
+        {
+            let mut node_mut = node.lock().unwrap();
+            for (i, action_util) in player_action_utils.iter().enumerate() {
+                let regret: f64 = action_util - node_util[player.index()];
+                node_mut.add_regret_sum(i, regret, counterfactual_reach);
+            }
         }
 
         node_util
     }
 
-    fn train_one_epoch(&mut self) -> f64 {
-        let initial = self.game.new_root();
-        self.cfr(&initial, [1.0, 1.0])[PlayerId::Player(0).index()]
+    /// Dumps the solved blueprint to `path` as JSON: the full info-set →
+    /// average-strategy table plus `num_replays` concrete trajectories sampled
+    /// from the root. Info sets are emitted in the same sorted order as
+    /// [`Self::print_nodes`] so diffs across training runs stay meaningful.
+    ///
+    /// Each sampled game records, per step, the info set reached, the legal
+    /// actions with their average-strategy probabilities, the action actually
+    /// taken, and finally the terminal payouts — mirroring the replay records an
+    /// external viewer consumes.
+    pub fn export_json<R: Rng>(
+        &self,
+        path: impl AsRef<std::path::Path>,
+        num_replays: usize,
+        rng: &mut R,
+    ) {
+        let strategy = self.strategy_table();
+        let replays: Vec<serde_json::Value> =
+            (0..num_replays).map(|_| self.sample_replay(rng)).collect();
+        let doc = serde_json::json!({
+            "strategy": strategy,
+            "replays": replays,
+        });
+        let file = std::fs::File::create(path).expect("failed to create strategy export file");
+        serde_json::to_writer_pretty(file, &doc).expect("failed to serialize strategy export");
+    }
+
+    /// The info-set → average-strategy table as an ordered JSON object, sorted
+    /// by info set like [`Self::print_nodes`].
+    fn strategy_table(&self) -> serde_json::Value {
+        let nodes = self.nodes.lock().unwrap();
+        let mut entries: Vec<SharedNode<G>> = nodes.values().map(Arc::clone).collect();
+        drop(nodes);
+        entries.sort_by(|a, b| a.lock().unwrap().cmp(&b.lock().unwrap()));
+
+        let mut table = serde_json::Map::new();
+        for node in entries {
+            let node = node.lock().unwrap();
+            let record = node.to_record();
+            table.insert(
+                record.info_set.clone(),
+                serde_json::json!({
+                    "actions": record.actions,
+                    "probabilities": record.average_strategy,
+                    "regret_sum": record.regret_sum,
+                }),
+            );
+        }
+        serde_json::Value::Object(table)
+    }
+
+    /// Plays one game from the root to a terminal node, sampling chance actions
+    /// with [`Game::sample_chance_action`] and player actions by the current average
+    /// strategy, and returns the recorded trajectory.
+    fn sample_replay<R: Rng>(&self, rng: &mut R) -> serde_json::Value {
+        let mut state = self.game.new_root();
+        let mut steps: Vec<serde_json::Value> = Vec::new();
+        while !self.game.is_terminal(&state) {
+            if self.game.get_node_player_id(&state) == PlayerId::Chance {
+                let action = self.game.sample_chance_action(rng, &state);
+                state = self.game.with_action(&state, action);
+                continue;
+            }
+            let info_set = self.game.to_info_set(&state);
+            let actions = self.game.list_legal_actions(&state);
+            let probabilities = self
+                .get_strategy(&info_set)
+                .unwrap_or_else(|| vec![1.0 / actions.len() as f64; actions.len()]);
+            let dist = WeightedIndex::new(&probabilities).unwrap();
+            let taken = actions[dist.sample(rng)];
+            steps.push(serde_json::json!({
+                "info_set": format!("{}", info_set),
+                "actions": actions.iter().map(|a| format!("{}", a)).collect::<Vec<_>>(),
+                "probabilities": probabilities,
+                "taken": format!("{}", taken),
+            }));
+            state = self.game.with_action(&state, taken);
+        }
+        serde_json::json!({
+            "steps": steps,
+            "payouts": self.game.get_payouts(&state),
+        })
     }
 
     fn print_nodes(&self) {
-        let nodes = self.nodes.borrow();
-        let mut nodes: Vec<_> = nodes.values().collect();
-        nodes.sort();
+        let nodes = self.nodes.lock().unwrap();
+        let mut nodes: Vec<_> = nodes.values().map(Arc::clone).collect();
+        nodes.sort_by(|a, b| a.lock().unwrap().cmp(&b.lock().unwrap()));
         info!("Nodes [");
         for node in nodes {
-            info!("    {}", node.borrow());
+            info!("    {}", node.lock().unwrap());
         }
         info!("]");
     }
 }
 
+impl<G> Trainer<G>
+where
+    G: Game + Sync,
+    G::State: Send + Sync,
+    G::InfoSet: Send + Sync,
+    G::Action: Send + Sync,
+{
+    /// Runs one CFR iteration, fanning the root's independent chance/sub-tree
+    /// children out across `self.threads` worker threads. Each worker recurses
+    /// into a disjoint slice of the children and accumulates regrets into the
+    /// per-node locks; the slices' utilities are summed at the end.
+    fn train_one_epoch_parallel(&self) -> f64 {
+        let root = self.game.new_root();
+        self.touched_nodes_count.fetch_add(1, Ordering::Relaxed);
+
+        // Only a chance root decomposes into independent sub-trees; anything
+        // else falls back to the serial traversal.
+        let num_players = self.game.player_count();
+        if self.threads <= 1 || self.game.get_node_player_id(&root) != PlayerId::Chance {
+            return self.cfr(&root, &vec![1.0; num_players], 1.0)[PlayerId::Player(0).index()];
+        }
+
+        let actions = self.game.list_legal_chance_actions(&root);
+        let mut chunks: Vec<Vec<(G::Action, f64)>> =
+            (0..self.threads).map(|_| Vec::new()).collect();
+        for (i, action) in actions.into_iter().enumerate() {
+            chunks[i % self.threads].push(action);
+        }
+
+        let root_ref = &root;
+        let me = &*self;
+        let mut node_util = vec![0.0f64; num_players];
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = chunks
+                .into_iter()
+                .filter(|chunk| !chunk.is_empty())
+                .map(|chunk| {
+                    scope.spawn(move || {
+                        let mut util = vec![0.0f64; num_players];
+                        for (act, prob) in chunk {
+                            let next_state = me.game.with_action(root_ref, act);
+                            // Root chance move: players start at reach 1.0 and the
+                            // branch probability is carried in the chance slot.
+                            let next_reach_probs = vec![1.0; num_players];
+                            let action_util = me.cfr(&next_state, &next_reach_probs, prob);
+                            for (player, player_action_util) in action_util.iter().enumerate() {
+                                util[player] += prob * player_action_util;
+                            }
+                        }
+                        util
+                    })
+                })
+                .collect();
+            for handle in handles {
+                let util = handle.join().unwrap();
+                for (player, u) in util.iter().enumerate() {
+                    node_util[player] += u;
+                }
+            }
+        });
+
+        node_util[PlayerId::Player(0).index()]
+    }
+}
+
 impl<G: Game> Strategy<G> for Trainer<G> {
     fn get_strategy(&self, info_set: &<G as Game>::InfoSet) -> Option<Vec<f64>> {
-        Some(self.nodes.borrow().get(info_set).unwrap().borrow().to_average_strategy())
+        Some(self.nodes.lock().unwrap().get(info_set).unwrap().lock().unwrap().to_average_strategy())
     }
 }
 
-impl<G: Game> Solver<G> for Trainer<G> {
+impl<G> Solver<G> for Trainer<G>
+where
+    G: Game + Sync,
+    G::State: Send + Sync,
+    G::InfoSet: Send + Sync,
+    G::Action: Send + Sync,
+{
     type SolverArgs = SolverArgs;
 
-    fn new(game: G, _args: Self::SolverArgs) -> Self {
+    fn new(game: G, args: Self::SolverArgs) -> Self {
         Trainer {
             game,
-            nodes: Rc::new(RefCell::new(HashMap::new())),
-            touched_nodes_count: 0,
+            nodes: Arc::new(Mutex::new(HashMap::new())),
+            touched_nodes_count: AtomicUsize::new(0),
+            threads: args.threads.max(1),
         }
     }
 
@@ -150,11 +345,11 @@ impl<G: Game> Solver<G> for Trainer<G> {
     }
 
     fn get_touched_nodes_count(&self) -> usize {
-        self.touched_nodes_count
+        self.touched_nodes_count.load(Ordering::Relaxed)
     }
 
     fn train_one_epoch(&mut self) -> f64 {
-        self.train_one_epoch()
+        self.train_one_epoch_parallel()
     }
 
     fn print_strategy(&self) {
@@ -70,9 +70,36 @@ where
         self.strategy_sum.iter().map(|s| s / normalizing_sum).collect()
     }
 
-    pub fn add_regret_sum(&mut self, action_index: usize, regret: f64, opponent_prob: f64) {
-        self.regret_sum[action_index] += opponent_prob * regret;
+    /// Accumulates counterfactual regret for `action_index`, weighting the
+    /// instantaneous `regret` by `counterfactual_reach` — the product of every
+    /// other player's reach probability to this node and the chance reach. In
+    /// the heads-up case this is the single opponent's reach probability times
+    /// the chance reach.
+    pub fn add_regret_sum(&mut self, action_index: usize, regret: f64, counterfactual_reach: f64) {
+        self.regret_sum[action_index] += counterfactual_reach * regret;
     }
+
+    /// A stable, language-agnostic view of this node for JSON export. The info
+    /// set and the actions are rendered through their `Display` impls so the
+    /// schema does not depend on `G::InfoSet`/`G::Action` being `Serialize`;
+    /// external tools key on the `info_set` string exactly as the logs print it.
+    pub fn to_record(&self) -> NodeRecord {
+        NodeRecord {
+            info_set: self.info_set.to_string(),
+            actions: self.actions.iter().map(|a| a.to_string()).collect(),
+            average_strategy: self.to_average_strategy(),
+            regret_sum: self.regret_sum.clone(),
+        }
+    }
+}
+
+/// One information set's solved strategy, as emitted to JSON.
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct NodeRecord {
+    pub info_set: String,
+    pub actions: Vec<String>,
+    pub average_strategy: Vec<f64>,
+    pub regret_sum: Vec<f64>,
 }
 
 impl<G> std::cmp::Eq for Node<G> where G: Game {}
@@ -0,0 +1,248 @@
+use std::collections::HashMap;
+
+use clap::Args;
+use log::info;
+use rand::{
+    Rng,
+    SeedableRng,
+};
+use rand_distr::{
+    Distribution,
+    Normal,
+};
+use wyhash::WyRng;
+
+use crate::{
+    eval::{
+        compute_exploitability,
+        Strategy,
+    },
+    games::{
+        Game,
+        PlayerId,
+    },
+};
+
+use super::Solver;
+
+#[derive(Args)]
+pub struct SolverArgs {
+    #[clap(long, value_parser, default_value_t = 50)]
+    population: usize,
+
+    /// Standard deviation of the Gaussian mutation applied to each probability.
+    #[clap(long, value_parser, default_value_t = 0.1)]
+    sigma: f64,
+
+    /// Probability that a given probability entry is mutated each generation.
+    #[clap(long, value_parser, default_value_t = 0.2)]
+    mutation_rate: f64,
+
+    /// Number of top members carried unchanged into the next generation.
+    #[clap(long, value_parser, default_value_t = 2)]
+    elitism: usize,
+
+    #[clap(long, value_parser, default_value_t = 42)]
+    seed: u64,
+}
+
+type Member<G> = HashMap<<G as Game>::InfoSet, Vec<f64>>;
+
+/// An evolutionary optimizer over per-info-set action distributions.
+///
+/// Each population member is a full strategy; fitness is the negative
+/// exploitability (less exploitable strategies breed more often). Parents are
+/// blended per info set by their fitness-proportional weights, then perturbed
+/// with Gaussian noise and renormalized.
+pub struct Trainer<G>
+where
+    G: Game,
+{
+    game: G,
+    /// The legal-action count of every reachable info set, in a stable order.
+    skeleton: Vec<(G::InfoSet, usize)>,
+    population: Vec<Member<G>>,
+    sigma: f64,
+    mutation_rate: f64,
+    elitism: usize,
+    rng: WyRng,
+
+    best: Member<G>,
+    touched_nodes_count: usize,
+}
+
+fn enumerate_info_sets<G: Game>(
+    game: &G,
+    state: &G::State,
+    out: &mut Vec<(G::InfoSet, usize)>,
+    seen: &mut std::collections::HashSet<G::InfoSet>,
+) {
+    if game.is_terminal(state) {
+        return;
+    }
+    if game.get_node_player_id(state) == PlayerId::Chance {
+        for (act, _prob) in game.list_legal_chance_actions(state) {
+            enumerate_info_sets(game, &game.with_action(state, act), out, seen);
+        }
+        return;
+    }
+    let info_set = game.to_info_set(state);
+    let actions = game.list_legal_actions(state);
+    if seen.insert(info_set.clone()) {
+        out.push((info_set, actions.len()));
+    }
+    for act in actions {
+        enumerate_info_sets(game, &game.with_action(state, act), out, seen);
+    }
+}
+
+impl<G> Trainer<G>
+where
+    G: Game,
+{
+    fn uniform_member(&self) -> Member<G> {
+        self.skeleton
+            .iter()
+            .map(|(info_set, len)| (info_set.clone(), vec![1.0 / *len as f64; *len]))
+            .collect()
+    }
+
+    /// Gaussian-perturbs every probability (with probability `mutation_rate`),
+    /// clamps negatives to zero and renormalizes to a valid distribution.
+    fn mutate(&mut self, member: &mut Member<G>) {
+        let normal = Normal::new(0.0, self.sigma).unwrap();
+        for probs in member.values_mut() {
+            for p in probs.iter_mut() {
+                if self.rng.gen::<f64>() < self.mutation_rate {
+                    *p = (*p + normal.sample(&mut self.rng)).max(0.0);
+                }
+            }
+            let sum: f64 = probs.iter().sum();
+            if sum > 0.0 {
+                for p in probs.iter_mut() {
+                    *p /= sum;
+                }
+            } else {
+                let len = probs.len();
+                probs.fill(1.0 / len as f64);
+            }
+        }
+    }
+
+    /// Blends two parents per info set by their normalized fitness weights.
+    fn breed(&self, a: &Member<G>, fit_a: f64, b: &Member<G>, fit_b: f64) -> Member<G> {
+        let total = fit_a + fit_b;
+        let (w_a, w_b) = if total > 0.0 {
+            (fit_a / total, fit_b / total)
+        } else {
+            (0.5, 0.5)
+        };
+        self.skeleton
+            .iter()
+            .map(|(info_set, len)| {
+                let uniform = vec![1.0 / *len as f64; *len];
+                let pa = a.get(info_set).unwrap_or(&uniform);
+                let pb = b.get(info_set).unwrap_or(&uniform);
+                let child: Vec<f64> =
+                    (0..*len).map(|i| w_a * pa[i] + w_b * pb[i]).collect();
+                (info_set.clone(), child)
+            })
+            .collect()
+    }
+}
+
+impl<G: Game> Strategy<G> for Trainer<G> {
+    fn get_strategy(&self, info_set: &<G as Game>::InfoSet) -> Option<Vec<f64>> {
+        self.best.get(info_set).cloned()
+    }
+}
+
+impl<G: Game> Solver<G> for Trainer<G> {
+    type SolverArgs = SolverArgs;
+
+    fn new(game: G, args: Self::SolverArgs) -> Self {
+        let mut skeleton = vec![];
+        let mut seen = std::collections::HashSet::new();
+        enumerate_info_sets(&game, &game.new_root(), &mut skeleton, &mut seen);
+
+        let mut trainer = Trainer {
+            game,
+            skeleton,
+            population: vec![],
+            sigma: args.sigma,
+            mutation_rate: args.mutation_rate,
+            elitism: args.elitism,
+            rng: WyRng::seed_from_u64(args.seed),
+            best: HashMap::new(),
+            touched_nodes_count: 0,
+        };
+        trainer.best = trainer.uniform_member();
+        trainer.population = (0..args.population)
+            .map(|_| {
+                let mut m = trainer.uniform_member();
+                trainer.mutate(&mut m);
+                m
+            })
+            .collect();
+        trainer
+    }
+
+    fn game_ref(&self) -> &G {
+        &self.game
+    }
+
+    fn get_touched_nodes_count(&self) -> usize {
+        self.touched_nodes_count
+    }
+
+    fn train_one_epoch(&mut self) -> f64 {
+        // Fitness = -exploitability. Shift to non-negative for selection weights.
+        let exploitabilities: Vec<f64> =
+            self.population.iter().map(|m| compute_exploitability(&self.game, m)).collect();
+        self.touched_nodes_count += self.population.len();
+
+        let mut ranked: Vec<usize> = (0..self.population.len()).collect();
+        ranked.sort_by(|&i, &j| exploitabilities[i].total_cmp(&exploitabilities[j]));
+
+        let best_idx = ranked[0];
+        self.best = self.population[best_idx].clone();
+        let best_exploitability = exploitabilities[best_idx];
+
+        let worst = exploitabilities.iter().cloned().fold(f64::MIN, f64::max);
+        // Higher is better; least-exploitable member gets the largest weight.
+        let fitness: Vec<f64> = exploitabilities.iter().map(|e| worst - e + 1e-9).collect();
+
+        let mut next: Vec<Member<G>> =
+            ranked.iter().take(self.elitism).map(|&i| self.population[i].clone()).collect();
+
+        let weight_sum: f64 = fitness.iter().sum();
+        while next.len() < self.population.len() {
+            let i = self.roulette(&fitness, weight_sum);
+            let j = self.roulette(&fitness, weight_sum);
+            let mut child = self.breed(&self.population[i], fitness[i], &self.population[j], fitness[j]);
+            self.mutate(&mut child);
+            next.push(child);
+        }
+        self.population = next;
+
+        -best_exploitability
+    }
+
+    fn print_strategy(&self) {
+        info!("# of info sets: {}", self.best.len());
+        info!("best exploitability: {}", compute_exploitability(&self.game, &self.best));
+    }
+}
+
+impl<G: Game> Trainer<G> {
+    fn roulette(&mut self, weights: &[f64], weight_sum: f64) -> usize {
+        let mut r = self.rng.gen::<f64>() * weight_sum;
+        for (i, w) in weights.iter().enumerate() {
+            r -= w;
+            if r <= 0.0 {
+                return i;
+            }
+        }
+        weights.len() - 1
+    }
+}
@@ -5,24 +5,42 @@ use crate::{
 };
 use clap::Args;
 use log::info;
-use rand::SeedableRng;
+use rand::{Rng, SeedableRng};
 use rand_distr::{Distribution, WeightedIndex};
 use wyhash::WyRng;
 
-use std::{cell::RefCell, collections::HashMap, rc::Rc};
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+};
 
 use super::node::Node;
 
+type SharedNode<G> = Arc<Mutex<Node<G>>>;
+type NodeStore<G> = Arc<Mutex<HashMap<<G as Game>::InfoSet, SharedNode<G>>>>;
+
 #[derive(Args)]
 pub struct SolverArgs {
     #[clap(long, short, value_parser, default_value_t = 42)]
     seed: u64,
+
+    /// Number of worker threads running independent external-sampling
+    /// traversals per epoch. `1` keeps the single-threaded traversal. Results
+    /// are deterministic on a best-effort basis only: the per-worker RNG seeds
+    /// are derived deterministically, but the order regret updates land in the
+    /// shared node table across threads is not fixed.
+    #[clap(long, value_parser, default_value_t = 1)]
+    threads: usize,
 }
 
 impl Default for SolverArgs {
     fn default() -> Self {
         SolverArgs {
             seed: 42,
+            threads: 1,
         }
     }
 }
@@ -32,30 +50,29 @@ where
     G: Game,
 {
     game: G,
-    nodes: Rc<RefCell<HashMap<G::InfoSet, Rc<RefCell<Node<G>>>>>>,
-    rng: WyRng,
+    nodes: NodeStore<G>,
+    seed: u64,
+    threads: usize,
+    epoch: AtomicU64,
 
-    touched_nodes_count: usize,
+    touched_nodes_count: AtomicUsize,
 }
 
 impl<G> Trainer<G>
 where
     G: Game,
 {
-    pub fn train_one_epoch(&mut self) -> f64 {
-        let mut p0_util = 0.0;
-        let initial = self.game.new_root();
-        for traverser in 0..=1 {
-            let util = self.sampling(&initial, PlayerId::Player(traverser));
-            if traverser == 0 {
-                p0_util = util;
-            }
-        }
-        p0_util
+    /// Fetches (or lazily creates) the shared node for `state`'s info set,
+    /// holding the map lock only for the lookup/insert.
+    fn node_for(&self, state: &G::State) -> SharedNode<G> {
+        let mut map = self.nodes.lock().unwrap();
+        Arc::clone(map.entry(self.game.to_info_set(state)).or_insert_with(|| {
+            Arc::new(Mutex::new(Node::new(self.game.list_legal_actions(state))))
+        }))
     }
 
-    pub fn sampling(&mut self, state: &G::State, traverser_id: PlayerId) -> f64 {
-        self.touched_nodes_count += 1;
+    pub fn sampling<R: Rng>(&self, state: &G::State, traverser_id: PlayerId, rng: &mut R) -> f64 {
+        self.touched_nodes_count.fetch_add(1, Ordering::Relaxed);
 
         if self.game.is_terminal(state) {
             return self.game.get_payouts(state)[traverser_id.index()];
@@ -64,22 +81,21 @@ where
         let player = self.game.get_node_player_id(state);
 
         if player == PlayerId::Chance {
-            // Sample an chance action and traverse its sub-tree.
-            let action = self.game.sample_chance_action(&mut self.rng, state);
+            // Sample a chance action and traverse its sub-tree.
+            let action = self.game.sample_chance_action(rng, state);
             let next_state = self.game.with_action(state, action);
-            return self.sampling(&next_state, traverser_id);
+            return self.sampling(&next_state, traverser_id, rng);
         }
 
-        let node = Rc::clone(
-            self.nodes.borrow_mut().entry(self.game.to_info_set(state)).or_insert_with(|| {
-                let node = Node::new(self.game.list_legal_actions(state));
-                Rc::new(RefCell::new(node))
-            }),
-        );
-        let mut node_mut = node.borrow_mut();
-        node_mut.regret_matching();
-        let strategy = node_mut.get_strategy();
-        let actions = node_mut.get_actions();
+        let node = self.node_for(state);
+
+        // Snapshot the regret-matched strategy under the lock, then release it so
+        // sibling threads can progress on other info sets while we recurse.
+        let (actions, strategy) = {
+            let mut node_mut = node.lock().unwrap();
+            node_mut.regret_matching();
+            (node_mut.get_actions().to_vec(), node_mut.get_strategy().to_vec())
+        };
         debug_assert_eq!(strategy.len(), actions.len());
 
         if player == traverser_id {
@@ -88,12 +104,13 @@ where
             // Compute action utils
             for (i, act) in actions.iter().enumerate() {
                 let next_state = self.game.with_action(state, *act);
-                let act_util = self.sampling(&next_state, traverser_id);
+                let act_util = self.sampling(&next_state, traverser_id, rng);
                 act_utils.push(act_util);
                 util += strategy[i] * act_util;
             }
 
             // Compute sampled counter factual regret values for each action.
+            let mut node_mut = node.lock().unwrap();
             for (i, act_util) in act_utils.iter().enumerate() {
                 let act_regret = act_util - util;
                 node_mut.regret_sum[i] += act_regret;
@@ -101,44 +118,111 @@ where
             util
         } else {
             // The current player is not the traverser
-            let action_index = self.sample_index(strategy);
+            let action_index = sample_index(rng, &strategy);
             let action = actions[action_index];
             let next_state = self.game.with_action(state, action);
-            let util = self.sampling(&next_state, traverser_id);
+            let util = self.sampling(&next_state, traverser_id, rng);
 
             // Update strategy sum so that we can calculate average strategy.
             // Note that the average strategy is updated on the opponent’s turns to enforce the
             // unbiasedness of the update to the average strategy.
             // (the reach probability of the current history is biased by the opponent's strategy)
-            node_mut.update_strategy_sum();
+            node.lock().unwrap().update_strategy_sum();
 
             util
         }
     }
+}
+
+impl<G> Trainer<G>
+where
+    G: Game + Sync,
+    G::State: Send + Sync,
+    G::InfoSet: Send + Sync,
+    G::Action: Send + Sync,
+{
+    /// Runs one training epoch. With `threads == 1` a single traversal per
+    /// traverser runs on the calling thread; otherwise `threads` workers each
+    /// run an independent external-sampling epoch from a fresh chance root,
+    /// accumulating regret/strategy sums into the shared per-node locks. The
+    /// merged `strategy_sum` has the same average-strategy semantics as the
+    /// serial path — it simply aggregates more sampled traversals per epoch.
+    fn train_one_epoch_parallel(&self) -> f64 {
+        let epoch = self.epoch.fetch_add(1, Ordering::Relaxed);
+        let workers = self.threads.max(1);
+
+        if workers == 1 {
+            let mut rng = WyRng::seed_from_u64(self.seed ^ epoch);
+            return self.run_traversals(&mut rng);
+        }
 
-    fn sample_index(&mut self, probs: &[f64]) -> usize {
-        let dist = WeightedIndex::new(probs).unwrap_or_else(|e| {
-            panic!("Invalid weights: e: {} probs: {:?}", e, probs);
+        let me = &*self;
+        let mut p0_util = 0.0;
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..workers)
+                .map(|w| {
+                    // Derive a distinct, deterministic seed per (epoch, worker).
+                    let worker_seed = me.seed ^ epoch.wrapping_mul(workers as u64).wrapping_add(w as u64);
+                    scope.spawn(move || {
+                        let mut rng = WyRng::seed_from_u64(worker_seed);
+                        me.run_traversals(&mut rng)
+                    })
+                })
+                .collect();
+            let mut sum = 0.0;
+            for handle in handles {
+                sum += handle.join().unwrap();
+            }
+            p0_util = sum / workers as f64;
         });
-        dist.sample(&mut self.rng)
+        p0_util
+    }
+
+    /// One external-sampling epoch: traverse once per traverser, returning the
+    /// traverser-0 utility.
+    fn run_traversals<R: Rng>(&self, rng: &mut R) -> f64 {
+        let mut p0_util = 0.0;
+        let initial = self.game.new_root();
+        for traverser in 0..=1 {
+            let util = self.sampling(&initial, PlayerId::Player(traverser), rng);
+            if traverser == 0 {
+                p0_util = util;
+            }
+        }
+        p0_util
     }
 }
 
+fn sample_index<R: Rng>(rng: &mut R, probs: &[f64]) -> usize {
+    let dist = WeightedIndex::new(probs).unwrap_or_else(|e| {
+        panic!("Invalid weights: e: {} probs: {:?}", e, probs);
+    });
+    dist.sample(rng)
+}
+
 impl<G: Game> Strategy<G> for Trainer<G> {
     fn get_strategy(&self, info_set: &<G as Game>::InfoSet) -> Option<Vec<f64>> {
-        self.nodes.borrow().get(info_set).map(|node| node.borrow().to_average_strategy())
+        self.nodes.lock().unwrap().get(info_set).map(|node| node.lock().unwrap().to_average_strategy())
     }
 }
 
-impl<G: Game> Solver<G> for Trainer<G> {
+impl<G> Solver<G> for Trainer<G>
+where
+    G: Game + Sync,
+    G::State: Send + Sync,
+    G::InfoSet: Send + Sync,
+    G::Action: Send + Sync,
+{
     type SolverArgs = SolverArgs;
 
     fn new(game: G, args: Self::SolverArgs) -> Self {
         Trainer {
             game,
-            nodes: Rc::new(RefCell::new(HashMap::new())),
-            rng: WyRng::seed_from_u64(args.seed),
-            touched_nodes_count: 0,
+            nodes: Arc::new(Mutex::new(HashMap::new())),
+            seed: args.seed,
+            threads: args.threads.max(1),
+            epoch: AtomicU64::new(0),
+            touched_nodes_count: AtomicUsize::new(0),
         }
     }
 
@@ -147,20 +231,15 @@ impl<G: Game> Solver<G> for Trainer<G> {
     }
 
     fn get_touched_nodes_count(&self) -> usize {
-        self.touched_nodes_count
+        self.touched_nodes_count.load(Ordering::Relaxed)
     }
 
     fn train_one_epoch(&mut self) -> f64 {
-        self.train_one_epoch()
+        self.train_one_epoch_parallel()
     }
 
     fn print_strategy(&self) {
-        let nodes = self.nodes.borrow();
+        let nodes = self.nodes.lock().unwrap();
         info!("# of nodes: {}", nodes.len());
-        /*
-        for node in nodes.iter().take(100) {
-            info!("  {:?}", node.0);
-        }
-         */
     }
 }
@@ -0,0 +1,240 @@
+use std::collections::HashMap;
+
+use clap::Args;
+use log::info;
+use rand::{
+    Rng,
+    SeedableRng,
+};
+use wyhash::WyRng;
+
+use crate::{
+    eval::Strategy,
+    games::{
+        Game,
+        PlayerId,
+    },
+};
+
+use super::Solver;
+
+#[derive(Args)]
+pub struct SolverArgs {
+    /// The UCB1 exploration constant `c`. Higher values widen the search at the
+    /// cost of exploiting promising lines less aggressively.
+    #[clap(long, value_parser, default_value_t = std::f64::consts::SQRT_2)]
+    exploration: f64,
+
+    #[clap(long, value_parser, default_value_t = 42)]
+    seed: u64,
+}
+
+/// One search-tree node, keyed in the arena by its `G::State`. Player nodes
+/// store a per-action visit count and cumulative payoff sum for their acting
+/// player, mirroring the regret-minimization [`Node`](crate::Node)'s
+/// `regret_sum`/`strategy_sum` layout. Chance nodes carry no statistics; they
+/// are sampled from rather than selected with UCB1.
+struct McNode<G: Game> {
+    player: PlayerId,
+    actions: Vec<G::Action>,
+    /// Visits to each action, parallel to `actions`.
+    action_visits: Vec<f64>,
+    /// Cumulative payoff to `player` collected through each action.
+    action_value_sum: Vec<f64>,
+    visits: f64,
+}
+
+impl<G: Game> McNode<G> {
+    fn new(player: PlayerId, actions: Vec<G::Action>) -> McNode<G> {
+        let len = actions.len();
+        McNode {
+            player,
+            actions,
+            action_visits: vec![0.0; len],
+            action_value_sum: vec![0.0; len],
+            visits: 0.0,
+        }
+    }
+
+    /// The first action that has never been tried, if any.
+    fn first_unexplored(&self) -> Option<usize> {
+        self.action_visits.iter().position(|v| *v == 0.0)
+    }
+
+    /// The action index maximizing the UCB1 score
+    /// `mean_payoff + c * sqrt(ln(parent_visits) / child_visits)`.
+    fn ucb1_select(&self, exploration: f64) -> usize {
+        let ln_parent = self.visits.ln();
+        let mut best = 0;
+        let mut best_score = f64::NEG_INFINITY;
+        for i in 0..self.actions.len() {
+            let mean = self.action_value_sum[i] / self.action_visits[i];
+            let score = mean + exploration * (ln_parent / self.action_visits[i]).sqrt();
+            if score > best_score {
+                best_score = score;
+                best = i;
+            }
+        }
+        best
+    }
+}
+
+/// Monte-Carlo Tree Search.
+///
+/// An online search agent that grows a tree keyed by `G::State`, selecting with
+/// UCB1, expanding one unexplored action at a time, evaluating leaves with a
+/// uniform-random rollout and backpropagating [`Game::get_payouts`] up the
+/// path. Because the arena is keyed by state, the subtree rooted at any reached
+/// state is reused across successive `train_one_epoch` calls (tree reuse), so
+/// repeated queries from the same position amortize.
+pub struct Trainer<G>
+where
+    G: Game,
+{
+    game: G,
+    arena: HashMap<G::State, McNode<G>>,
+    /// Visit-count policy per info set, refreshed from the arena after each
+    /// epoch so the solver doubles as a [`Strategy`].
+    policy: HashMap<G::InfoSet, Vec<f64>>,
+    exploration: f64,
+    rng: WyRng,
+    touched_nodes_count: usize,
+}
+
+impl<G> Trainer<G>
+where
+    G: Game,
+{
+    /// Runs a single MCTS iteration from `state`, returning the per-player
+    /// payoff vector observed on this iteration (either from expansion+rollout
+    /// or from the terminal itself).
+    fn iterate(&mut self, state: &G::State) -> Vec<f64> {
+        self.touched_nodes_count += 1;
+
+        if self.game.is_terminal(state) {
+            return self.game.get_payouts(state);
+        }
+
+        let player = self.game.get_node_player_id(state);
+        if player == PlayerId::Chance {
+            // Chance nodes are sampled from their probability-weighted actions
+            // rather than selected with UCB1.
+            let action = self.game.sample_chance_action(&mut self.rng, state);
+            let next_state = self.game.with_action(state, action);
+            return self.iterate(&next_state);
+        }
+
+        // Ensure this node exists in the arena (tree growth / reuse).
+        if !self.arena.contains_key(state) {
+            let actions = self.game.list_legal_actions(state);
+            self.arena.insert(state.clone(), McNode::new(player, actions));
+        }
+
+        let (action_index, expand) = {
+            let node = self.arena.get(state).unwrap();
+            match node.first_unexplored() {
+                Some(i) => (i, true),
+                None => (node.ucb1_select(self.exploration), false),
+            }
+        };
+        let action = self.arena.get(state).unwrap().actions[action_index];
+        let next_state = self.game.with_action(state, action);
+
+        // Expanding a fresh action evaluates it with a single rollout; an
+        // already-expanded action recurses deeper into the tree.
+        let payouts = if expand {
+            self.rollout(&next_state)
+        } else {
+            self.iterate(&next_state)
+        };
+
+        let node = self.arena.get_mut(state).unwrap();
+        node.visits += 1.0;
+        node.action_visits[action_index] += 1.0;
+        node.action_value_sum[action_index] += payouts[player.index()];
+
+        payouts
+    }
+
+    /// A uniform-random playout from `state` to a terminal, returning its
+    /// payouts. Chance nodes sample from their weighted distribution.
+    fn rollout(&mut self, state: &G::State) -> Vec<f64> {
+        let mut state = state.clone();
+        while !self.game.is_terminal(&state) {
+            self.touched_nodes_count += 1;
+            let action = if self.game.get_node_player_id(&state) == PlayerId::Chance {
+                self.game.sample_chance_action(&mut self.rng, &state)
+            } else {
+                let actions = self.game.list_legal_actions(&state);
+                actions[self.rng.gen_range(0..actions.len())]
+            };
+            state = self.game.with_action(&state, action);
+        }
+        self.game.get_payouts(&state)
+    }
+
+    /// Rebuilds the info-set policy from the current arena, accumulating the
+    /// visit counts of every player node that shares an info set.
+    fn refresh_policy(&mut self) {
+        let mut counts: HashMap<G::InfoSet, Vec<f64>> = HashMap::new();
+        for (state, node) in self.arena.iter() {
+            if node.player == PlayerId::Chance {
+                continue;
+            }
+            let info_set = self.game.to_info_set(state);
+            let entry = counts.entry(info_set).or_insert_with(|| vec![0.0; node.actions.len()]);
+            for (i, v) in node.action_visits.iter().enumerate() {
+                entry[i] += v;
+            }
+        }
+        for visits in counts.values_mut() {
+            let sum: f64 = visits.iter().sum();
+            if sum > 0.0 {
+                for v in visits.iter_mut() {
+                    *v /= sum;
+                }
+            }
+        }
+        self.policy = counts;
+    }
+}
+
+impl<G: Game> Strategy<G> for Trainer<G> {
+    fn get_strategy(&self, info_set: &<G as Game>::InfoSet) -> Option<Vec<f64>> {
+        self.policy.get(info_set).cloned()
+    }
+}
+
+impl<G: Game> Solver<G> for Trainer<G> {
+    type SolverArgs = SolverArgs;
+
+    fn new(game: G, args: Self::SolverArgs) -> Self {
+        Trainer {
+            game,
+            arena: HashMap::new(),
+            policy: HashMap::new(),
+            exploration: args.exploration,
+            rng: WyRng::seed_from_u64(args.seed),
+            touched_nodes_count: 0,
+        }
+    }
+
+    fn game_ref(&self) -> &G {
+        &self.game
+    }
+
+    fn get_touched_nodes_count(&self) -> usize {
+        self.touched_nodes_count
+    }
+
+    fn train_one_epoch(&mut self) -> f64 {
+        let root = self.game.new_root();
+        let payouts = self.iterate(&root);
+        self.refresh_policy();
+        payouts[PlayerId::Player(0).index()]
+    }
+
+    fn print_strategy(&self) {
+        info!("# of search-tree nodes: {}", self.arena.len());
+    }
+}
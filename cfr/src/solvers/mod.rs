@@ -6,7 +6,10 @@ use crate::{
 };
 
 pub mod cfr;
+pub mod genetic;
 pub mod mccfr_external_sampling;
+pub mod mcts;
+pub mod qlearning;
 
 pub trait Solver<G: Game>: Strategy<G> {
     type SolverArgs: Args;
@@ -14,5 +17,6 @@ pub trait Solver<G: Game>: Strategy<G> {
     fn new(game: G, args: Self::SolverArgs) -> Self;
     fn game_ref(&self) -> &G;
     fn train_one_epoch(&mut self) -> f64;
+    fn get_touched_nodes_count(&self) -> usize;
     fn print_strategy(&self);
 }
@@ -0,0 +1,179 @@
+use std::collections::HashMap;
+
+use clap::Args;
+use log::info;
+use rand::{
+    Rng,
+    SeedableRng,
+};
+use wyhash::WyRng;
+
+use crate::{
+    eval::Strategy,
+    games::{
+        Game,
+        PlayerId,
+    },
+};
+
+use super::Solver;
+
+#[derive(Args)]
+pub struct SolverArgs {
+    #[clap(long, value_parser, default_value_t = 0.1)]
+    learning_rate: f64,
+
+    #[clap(long, value_parser, default_value_t = 0.1)]
+    exploration_prob: f64,
+
+    #[clap(long, value_parser, default_value_t = 1.0)]
+    discount_rate: f64,
+
+    #[clap(long, value_parser, default_value_t = 42)]
+    seed: u64,
+}
+
+/// Tabular Q-learning.
+///
+/// Unlike the regret-minimization solvers this keeps a table of action values
+/// `Q(s, a)` keyed by the information set and learns them with the standard TD
+/// update while rolling out episodes of self-play.
+pub struct Trainer<G>
+where
+    G: Game,
+{
+    game: G,
+    /// Action values keyed by info set. Each row is stored in
+    /// `list_legal_actions` order (the order it was first populated in) so the
+    /// positional [`Strategy`] vector returned by `get_strategy` lines up with
+    /// what every consumer indexes against.
+    q: HashMap<G::InfoSet, Vec<(G::Action, f64)>>,
+    learning_rate: f64,
+    exploration_prob: f64,
+    discount_rate: f64,
+    rng: WyRng,
+
+    touched_nodes_count: usize,
+}
+
+impl<G> Trainer<G>
+where
+    G: Game,
+{
+    fn q_value(&self, info_set: &G::InfoSet, action: &G::Action) -> f64 {
+        self.q
+            .get(info_set)
+            .and_then(|row| row.iter().find(|(a, _)| a == action).map(|(_, q)| *q))
+            .unwrap_or(0.0)
+    }
+
+    /// The largest `Q(s, a)` over the legal actions at `state`, or 0.0 when the
+    /// state is terminal (the terminal bootstrap term).
+    fn max_q(&self, state: &G::State) -> f64 {
+        if self.game.is_terminal(state) {
+            return 0.0;
+        }
+        let info_set = self.game.to_info_set(state);
+        self.game
+            .list_legal_actions(state)
+            .iter()
+            .map(|act| self.q_value(&info_set, act))
+            .fold(f64::NEG_INFINITY, f64::max)
+    }
+
+    /// Picks an action ε-greedily over the legal actions at `state`.
+    fn choose_action(&mut self, state: &G::State) -> G::Action {
+        let actions = self.game.list_legal_actions(state);
+        if self.rng.gen::<f64>() < self.exploration_prob {
+            return actions[self.rng.gen_range(0..actions.len())];
+        }
+        let info_set = self.game.to_info_set(state);
+        *actions
+            .iter()
+            .max_by(|a, b| {
+                self.q_value(&info_set, a).total_cmp(&self.q_value(&info_set, b))
+            })
+            .unwrap()
+    }
+}
+
+impl<G: Game> Strategy<G> for Trainer<G> {
+    fn get_strategy(&self, info_set: &<G as Game>::InfoSet) -> Option<Vec<f64>> {
+        let row = self.q.get(info_set)?;
+        // Rows are stored in `list_legal_actions` order, so iterating them yields
+        // a vector aligned to the positions every consumer indexes against.
+        let best = row.iter().map(|(_, q)| *q).fold(f64::NEG_INFINITY, f64::max);
+        let strategy: Vec<f64> = row
+            .iter()
+            .map(|(_, q)| if *q >= best { 1.0 } else { 0.0 })
+            .collect();
+        let sum: f64 = strategy.iter().sum();
+        Some(strategy.iter().map(|p| p / sum).collect())
+    }
+}
+
+impl<G: Game> Solver<G> for Trainer<G> {
+    type SolverArgs = SolverArgs;
+
+    fn new(game: G, args: Self::SolverArgs) -> Self {
+        Trainer {
+            game,
+            q: HashMap::new(),
+            learning_rate: args.learning_rate,
+            exploration_prob: args.exploration_prob,
+            discount_rate: args.discount_rate,
+            rng: WyRng::seed_from_u64(args.seed),
+            touched_nodes_count: 0,
+        }
+    }
+
+    fn game_ref(&self) -> &G {
+        &self.game
+    }
+
+    fn get_touched_nodes_count(&self) -> usize {
+        self.touched_nodes_count
+    }
+
+    fn train_one_epoch(&mut self) -> f64 {
+        let mut state = self.game.new_root();
+        while !self.game.is_terminal(&state) {
+            self.touched_nodes_count += 1;
+
+            if self.game.get_node_player_id(&state) == PlayerId::Chance {
+                let action = self.game.sample_chance_action(&mut self.rng, &state);
+                state = self.game.with_action(&state, action);
+                continue;
+            }
+
+            let player = self.game.get_node_player_id(&state);
+            let info_set = self.game.to_info_set(&state);
+            // Make sure the row exists so the action order is fixed on first visit.
+            let action = self.choose_action(&state);
+            let next_state = self.game.with_action(&state, action);
+
+            let reward = if self.game.is_terminal(&next_state) {
+                self.game.get_payouts(&next_state)[player.index()]
+            } else {
+                0.0
+            };
+            let target = reward + self.discount_rate * self.max_q(&next_state);
+            let old_q = self.q_value(&info_set, &action);
+            let legal_actions = self.game.list_legal_actions(&state);
+            let row = self
+                .q
+                .entry(info_set)
+                .or_insert_with(|| legal_actions.into_iter().map(|a| (a, 0.0)).collect());
+            if let Some(entry) = row.iter_mut().find(|(a, _)| *a == action) {
+                entry.1 = old_q + self.learning_rate * (target - old_q);
+            }
+
+            state = next_state;
+        }
+        self.game.get_payouts(&state)[PlayerId::Player(0).index()]
+    }
+
+    fn print_strategy(&self) {
+        info!("# of info sets: {}", self.q.len());
+    }
+}
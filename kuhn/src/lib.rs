@@ -1,20 +1,27 @@
 use std::{
     collections::HashMap,
     fmt::Display,
+    io::Write,
 };
 
 use log::info;
 use more_asserts::assert_ge;
-use rand::seq::SliceRandom;
+use rand::{
+    distributions::WeightedIndex,
+    prelude::Distribution,
+    seq::SliceRandom,
+    Rng,
+};
+use serde::Serialize;
 
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize)]
 pub enum Card {
     Jack = 0,
     Queen = 1,
     King = 2,
 }
 
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize)]
 pub enum Action {
     Pass,
     Bet,
@@ -162,6 +169,48 @@ impl Node {
             .map(|s| s / normalizing_sum)
             .collect()
     }
+
+    /// A stable, serializable view of this node: the info-set key (player, card,
+    /// action history), the action labels, the converged average strategy, and
+    /// the cumulative regret. Consumed by [`Trainer::dump_json`].
+    pub fn to_record(&self) -> NodeRecord {
+        NodeRecord {
+            player_id: self.info_set.player_id,
+            card: self.info_set.card,
+            history: self.info_set.actions,
+            actions: Action::VALUES.to_vec(),
+            average_strategy: self.to_average_strategy(),
+            regret_sum: self.regret_sum.clone(),
+        }
+    }
+}
+
+/// One information set's solved strategy, emitted to JSON for external
+/// visualizers and convergence trackers.
+#[derive(Serialize)]
+pub struct NodeRecord {
+    pub player_id: usize,
+    pub card: Card,
+    pub history: [Option<Action>; 2],
+    pub actions: Vec<Action>,
+    pub average_strategy: Vec<f64>,
+    pub regret_sum: Vec<f64>,
+}
+
+/// A single played-out hand: the dealt cards, the actions taken in order, and
+/// the terminal payout to player 0.
+#[derive(Serialize)]
+pub struct HandTrajectory {
+    pub cards: [Card; 2],
+    pub actions: Vec<Action>,
+    pub payout: f64,
+}
+
+/// The solved strategy plus an optional set of sampled playthroughs.
+#[derive(Serialize)]
+pub struct SolveReport {
+    pub nodes: Vec<NodeRecord>,
+    pub trajectories: Vec<HandTrajectory>,
 }
 
 impl Display for Node {
@@ -260,4 +309,67 @@ impl Trainer {
         }
         info!("]");
     }
+
+    /// Builds a [`SolveReport`] from the current node table and the supplied
+    /// sampled trajectories. Nodes are ordered by action history then card so
+    /// the JSON diffs cleanly across runs.
+    pub fn to_report(&self, trajectories: Vec<HandTrajectory>) -> SolveReport {
+        let mut nodes: Vec<NodeRecord> = self.nodes.values().map(Node::to_record).collect();
+        nodes.sort_by_key(|n| (n.history, n.card));
+        SolveReport {
+            nodes,
+            trajectories,
+        }
+    }
+
+    /// Writes the solved strategy and `trajectories` to `writer` as JSON.
+    pub fn dump_json<W: Write>(
+        &self,
+        writer: W,
+        trajectories: Vec<HandTrajectory>,
+    ) -> serde_json::Result<()> {
+        serde_json::to_writer_pretty(writer, &self.to_report(trajectories))
+    }
+
+    /// Samples `count` complete hands by dealing a random deck and playing each
+    /// seat from its converged average strategy, recording the cards, the
+    /// actions taken, and the payout to player 0.
+    pub fn sample_trajectories(&self, rng: &mut impl Rng, count: usize) -> Vec<HandTrajectory> {
+        (0..count).map(|_| self.sample_trajectory(rng)).collect()
+    }
+
+    fn sample_trajectory(&self, rng: &mut impl Rng) -> HandTrajectory {
+        let mut cards = [Card::Jack, Card::Queen, Card::King];
+        cards.shuffle(rng);
+        let dealt = [cards[0], cards[1]];
+
+        let mut state = State::new(dealt);
+        let mut actions = vec![];
+        while !state.is_terminal() {
+            let info_set = InfoSet::from(&state);
+            let strategy = self
+                .nodes
+                .get(&info_set)
+                .map(Node::to_average_strategy)
+                .unwrap_or_else(|| vec![1.0 / Action::COUNT as f64; Action::COUNT]);
+            let dist = WeightedIndex::new(&strategy).unwrap();
+            let act = Action::VALUES[dist.sample(rng)];
+            actions.push(act);
+            state = state.with_action(act);
+        }
+
+        // `get_payout_for_next_player` is from the perspective of whoever is to
+        // move at the terminal node; normalize it to player 0.
+        let payout = state.get_payout_for_next_player() as f64;
+        let payout = if state.next_player_id == 0 {
+            payout
+        } else {
+            -payout
+        };
+        HandTrajectory {
+            cards: dealt,
+            actions,
+            payout,
+        }
+    }
 }